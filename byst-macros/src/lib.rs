@@ -41,6 +41,23 @@ use crate::{
 /// }
 /// ```
 ///
+/// A default endianness can be specified for the whole struct. It's used for
+/// all fields that don't specify their own endianness or context. Derived
+/// impls without an explicit context accept any context, so structs deriving
+/// [`Read`][1] can be nested without specifying a context:
+///
+/// ```ignore
+/// # use byst_macros::Read;
+///
+/// #[derive(Read)]
+/// #[byst(big)]
+/// struct Header {
+///     length: u16,
+///     #[byst(little)]
+///     value: u32,
+/// }
+/// ```
+///
 /// [1]: skunk::util::bytes::rw::Read
 #[proc_macro_error]
 #[proc_macro_derive(Read, attributes(byst))]
@@ -34,6 +34,7 @@ pub use self::{
     read::DeriveRead,
     write::DeriveWrite,
 };
+use crate::util::ImplGenerics;
 
 #[derive(FromDeriveInput)]
 #[darling(attributes(byst), forward_attrs(allow, doc, cfg))]
@@ -44,6 +45,11 @@ pub struct DeriveOptions {
     pub context: Option<ContextDeriveOptions>,
     pub error: Option<Type>,
 
+    /// Default endianness for fields that don't specify their own endianness
+    /// or context.
+    #[darling(flatten)]
+    pub endianness: Endianness,
+
     pub tag: Option<TagDeriveOptions>,
     pub match_expr: Option<Expr>,
     #[darling(default)]
@@ -69,22 +75,27 @@ impl DeriveOptions {
             || self.tag.is_some()
             || self.match_expr.is_some()
             || self.no_wild
+            || self.endianness.ty().is_some()
         {
             abort_call_site!("No other options are valid when deriving as view.");
         }
     }
 
-    pub fn context(&self) -> (Ident, Type) {
+    /// Returns the name and type of the context.
+    ///
+    /// If no context is specified, the impl is generic over the context type
+    /// `__C`, which is then ignored. This way the type can be a field of a
+    /// type with a default endianness, without specifying a context for the
+    /// field.
+    pub fn context(&self, impl_generics: &mut ImplGenerics) -> (Ident, Type) {
         let (ident, ty) = if let Some(context) = &self.context {
-            (context.name.clone(), Some(context.ty.clone()))
+            (context.name.clone(), context.ty.clone())
         }
         else {
-            (None, None)
+            impl_generics.type_params.push(parse_quote! { __C });
+            (None, parse_quote! { __C })
         };
-        (
-            ident.unwrap_or_else(|| parse_quote! { __context }),
-            ty.unwrap_or_else(|| parse_quote! { () }),
-        )
+        (ident.unwrap_or_else(|| parse_quote! { __context }), ty)
     }
 
     pub fn tag_expr(&self, track: &mut DeriveBounds) -> Expr {
@@ -163,19 +174,22 @@ impl FieldOptions {
             }
             else {
                 let ty = &self.ty;
-                parse_quote_spanned! { self.span() => <#ty as ::std::default::Default>::default() }
+                parse_quote_spanned! { self.span() => <#ty as ::core::default::Default>::default() }
             }
         })
     }
 
-    pub fn context(&self) -> (Type, Expr) {
-        context(&self.endianness, self.context.as_ref())
+    pub fn context(&self, default_endianness: Option<&Type>) -> (Type, Expr) {
+        match (self.endianness.ty(), &self.context, default_endianness) {
+            (None, None, Some(endianness)) => (endianness.clone(), parse_quote! { #endianness }),
+            _ => context(&self.endianness, self.context.as_ref()),
+        }
     }
 
     pub fn map_err(&self) -> Path {
         self.map_err
             .clone()
-            .unwrap_or_else(|| parse_quote! { std::convert::identity })
+            .unwrap_or_else(|| parse_quote! { ::core::convert::identity })
     }
 }
 
@@ -189,7 +203,7 @@ fn context(endianness: &Endianness, context: Option<&ContextFieldOptions>) -> (T
                 context
                     .with
                     .clone()
-                    .unwrap_or_else(|| parse_quote! { ::std::default::Default::default() }),
+                    .unwrap_or_else(|| parse_quote! { ::core::default::Default::default() }),
             )
         }
         _ => abort_call_site!("Endianness can not be specified, when also specifying context."),
@@ -324,7 +338,7 @@ impl DeriveBounds {
 
         if let Some(error_ty) = &self.error_ty {
             self.where_clause.predicates.push(
-                parse_quote! { #error_ty: ::std::convert::From<<#field_ty as ::byst::io::#io_trait::<#io_ty, #context_ty>>::Error> },
+                parse_quote! { #error_ty: ::core::convert::From<<#field_ty as ::byst::io::#io_trait::<#io_ty, #context_ty>>::Error> },
             );
         }
         else {
@@ -337,7 +351,7 @@ impl DeriveBounds {
     pub fn finish(self) -> (WhereClause, Type) {
         let error_ty = self
             .error_ty
-            .unwrap_or_else(|| parse_quote! { ::std::convert::Infallible });
+            .unwrap_or_else(|| parse_quote! { ::core::convert::Infallible });
         (self.where_clause, error_ty)
     }
 }
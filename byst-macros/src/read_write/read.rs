@@ -16,6 +16,7 @@ use syn::{
     DataStruct,
     DeriveInput,
     Fields,
+    Type,
 };
 
 use super::{
@@ -60,17 +61,18 @@ impl Deriver for DeriveRead {
         options: Self::Options,
     ) -> Result<TokenStream, Error> {
         let ident = &item.ident;
-        let (context_name, context_ty) = options.context();
-
         let SplitGenerics {
             mut impl_generics,
             type_generics,
             where_clause,
         } = SplitGenerics::from_generics(&item.generics);
+        let (context_name, context_ty) = options.context(&mut impl_generics);
         impl_generics.type_params.push(parse_quote! { __R });
         let mut bounds = DeriveBounds::new(where_clause, options.error.clone());
 
-        let (read_fields, struct_init) = make_struct_init(&data.fields, &mut bounds)?;
+        let default_endianness = options.endianness.ty();
+        let (read_fields, struct_init) =
+            make_struct_init(&data.fields, default_endianness.as_ref(), &mut bounds)?;
 
         let (where_clause, error_ty) = bounds.finish();
 
@@ -79,9 +81,9 @@ impl Deriver for DeriveRead {
             impl #impl_generics ::byst::io::Read<__R, #context_ty> for #ident #type_generics #where_clause {
                 type Error = #error_ty;
 
-                fn read(mut __reader: &mut __R, #context_name: #context_ty) -> ::std::result::Result<Self, Self::Error> {
+                fn read(mut __reader: &mut __R, #context_name: #context_ty) -> ::core::result::Result<Self, Self::Error> {
                     #read_fields
-                    ::std::result::Result::Ok(Self #struct_init)
+                    ::core::result::Result::Ok(Self #struct_init)
                 }
             }
         })
@@ -93,17 +95,17 @@ impl Deriver for DeriveRead {
         options: Self::Options,
     ) -> Result<TokenStream, Error> {
         let ident = &item.ident;
-        let (context_name, context_ty) = options.context();
-
         let SplitGenerics {
             mut impl_generics,
             type_generics,
             where_clause,
         } = SplitGenerics::from_generics(&item.generics);
+        let (context_name, context_ty) = options.context(&mut impl_generics);
         impl_generics.type_params.push(parse_quote! { __R });
         let mut bounds = DeriveBounds::new(where_clause, options.error.clone());
 
         let tag_expr = options.tag_expr(&mut bounds);
+        let default_endianness = options.endianness.ty();
         let mut match_arms = Vec::with_capacity(data.variants.len());
 
         for variant in &data.variants {
@@ -111,7 +113,8 @@ impl Deriver for DeriveRead {
             let variant_name = &variant.ident;
             let pat = variant_options.pat();
 
-            let (read_fields, struct_init) = make_struct_init(&variant.fields, &mut bounds)?;
+            let (read_fields, struct_init) =
+                make_struct_init(&variant.fields, default_endianness.as_ref(), &mut bounds)?;
 
             match_arms.push(quote! {
                 #pat => {
@@ -124,7 +127,7 @@ impl Deriver for DeriveRead {
         if !options.no_wild {
             match_arms.push(quote! {
             _ => {
-                return ::std::result::Result::Err(::byst::io::InvalidDiscriminant(__tag).into());
+                return ::core::result::Result::Err(::byst::io::InvalidDiscriminant(__tag).into());
             },
         });
 
@@ -134,7 +137,7 @@ impl Deriver for DeriveRead {
 
             if let Some(error_ty) = &bounds.error_ty {
                 bounds.where_clause.predicates.push(
-                parse_quote! { #error_ty: ::std::convert::From<::byst::io::InvalidDiscriminant<#tag_ty>> },
+                parse_quote! { #error_ty: ::core::convert::From<::byst::io::InvalidDiscriminant<#tag_ty>> },
             );
             }
             else {
@@ -152,9 +155,9 @@ impl Deriver for DeriveRead {
             impl #impl_generics ::byst::io::Read<__R, #context_ty> for #ident #type_generics #where_clause {
                 type Error = #error_ty;
 
-                fn read(mut __reader: &mut __R, #context_name: #context_ty) -> ::std::result::Result<Self, Self::Error> {
+                fn read(mut __reader: &mut __R, #context_name: #context_ty) -> ::core::result::Result<Self, Self::Error> {
                     let __tag = #tag_expr;
-                    ::std::result::Result::Ok(
+                    ::core::result::Result::Ok(
                         match __tag {
                             #(#match_arms)*
                         }
@@ -167,6 +170,7 @@ impl Deriver for DeriveRead {
 
 fn make_struct_init(
     fields: &Fields,
+    default_endianness: Option<&Type>,
     bounds: &mut DeriveBounds,
 ) -> Result<(TokenStream, TokenStream), Error> {
     let mut read_fields = Vec::with_capacity(fields.len());
@@ -189,7 +193,7 @@ fn make_struct_init(
             });
         }
         else {
-            let (context_ty, context_expr) = field_options.context();
+            let (context_ty, context_expr) = field_options.context(default_endianness);
             let map_err = field_options.map_err();
 
             bounds.reads(field_ty, &context_ty);
@@ -242,11 +246,11 @@ fn derive_transparent(item: DeriveInput, options: DeriveOptions) -> Result<Token
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics ::byst::io::Read<__R, __C> for #ident #type_generics #where_clause {
-            type Error = ::std::convert::Infallible;
+            type Error = ::core::convert::Infallible;
 
             #[inline]
-            fn read(mut __reader: &mut __R, __context: __C) -> ::std::result::Result<Self, Self::Error> {
-                ::std::result::Result::Ok(__reader.rest())
+            fn read(mut __reader: &mut __R, __context: __C) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(__reader.rest())
             }
         }
     })
@@ -350,9 +354,9 @@ fn derive_read_for_struct_bitfield(
     Ok(quote! {
         #[automatically_derived]
         impl<__R, #impl_generics> ::byst::rw::Read<__R> for #ident<#type_generics> #where_clause {
-            fn read(mut reader: __R) -> ::std::result::Result<Self, ::byst::rw::End> {
+            fn read(mut reader: __R) -> ::core::result::Result<Self, ::byst::rw::End> {
                 let _value: #bitfield_ty = #read_value;
-                ::std::result::Result::Ok(Self {
+                ::core::result::Result::Ok(Self {
                     #(#struct_init)*
                 })
             }
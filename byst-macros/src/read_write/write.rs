@@ -7,6 +7,7 @@ use syn::{
     DataStruct,
     DeriveInput,
     Fields,
+    Type,
 };
 
 use super::{
@@ -51,17 +52,18 @@ impl Deriver for DeriveWrite {
         options.check_for_struct();
 
         let ident = &item.ident;
-        let (context_name, context_ty) = options.context();
-
         let SplitGenerics {
             mut impl_generics,
             type_generics,
             where_clause,
         } = SplitGenerics::from_generics(&item.generics);
+        let (context_name, context_ty) = options.context(&mut impl_generics);
         impl_generics.type_params.push(parse_quote! { __W });
         let mut bounds = DeriveBounds::new(where_clause, options.error.clone());
 
-        let write_fields = make_struct_write_fields(&data.fields, &mut bounds)?;
+        let default_endianness = options.endianness.ty();
+        let write_fields =
+            make_struct_write_fields(&data.fields, default_endianness.as_ref(), &mut bounds)?;
 
         let (where_clause, error_ty) = bounds.finish();
 
@@ -70,9 +72,9 @@ impl Deriver for DeriveWrite {
             impl #impl_generics ::byst::io::Write<__W, #context_ty> for #ident #type_generics #where_clause {
                 type Error = #error_ty;
 
-                fn write(&self, mut __writer: &mut __W, #context_name: #context_ty) -> ::std::result::Result<(), Self::Error> {
+                fn write(&self, mut __writer: &mut __W, #context_name: #context_ty) -> ::core::result::Result<(), Self::Error> {
                     #write_fields
-                    ::std::result::Result::Ok(())
+                    ::core::result::Result::Ok(())
                 }
             }
         })
@@ -81,6 +83,7 @@ impl Deriver for DeriveWrite {
 
 fn make_struct_write_fields(
     fields: &Fields,
+    default_endianness: Option<&Type>,
     bounds: &mut DeriveBounds,
 ) -> Result<TokenStream, Error> {
     let mut write_fields = Vec::with_capacity(fields.len());
@@ -98,7 +101,7 @@ fn make_struct_write_fields(
             // nop
         }
         else {
-            let (context_ty, context_expr) = field_options.context();
+            let (context_ty, context_expr) = field_options.context(default_endianness);
             let map_err = field_options.map_err();
 
             bounds.writes(field_ty, &context_ty);
@@ -0,0 +1,28 @@
+#![feature(test)]
+
+extern crate test;
+
+use byst::util::buf_eq;
+use test::{
+    black_box,
+    Bencher,
+};
+
+const SIZE: usize = 1024 * 1024;
+
+#[bench]
+fn buf_eq_equal(b: &mut Bencher) {
+    let left = vec![0xab; SIZE];
+    let right = left.clone();
+    b.bytes = SIZE as u64;
+    b.iter(|| buf_eq(black_box(&left), black_box(&right)));
+}
+
+#[bench]
+fn buf_eq_differs_in_last_byte(b: &mut Bencher) {
+    let left = vec![0xab; SIZE];
+    let mut right = left.clone();
+    right[SIZE - 1] = 0;
+    b.bytes = SIZE as u64;
+    b.iter(|| buf_eq(black_box(&left), black_box(&right)));
+}
@@ -0,0 +1,102 @@
+//! Implementations of the [`arbitrary`](::arbitrary) crate's traits.
+//!
+//! This is enabled with the `arbitrary` feature, and lets fuzz targets take
+//! [`Bytes`] and [`BytesMut`] as input.
+
+use ::arbitrary::{
+    Arbitrary,
+    Result,
+    Unstructured,
+};
+
+use crate::{
+    Buf,
+    Bytes,
+    BytesMut,
+};
+
+impl<'a> Arbitrary<'a> for Bytes {
+    /// Takes a length-prefixed byte slice from `u`.
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(<&[u8]>::arbitrary(u)?.to_bytes())
+    }
+
+    /// Takes all remaining bytes from `u`.
+    #[inline]
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Ok(<&[u8]>::arbitrary_take_rest(u)?.to_bytes())
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&[u8]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for BytesMut {
+    /// Takes a length-prefixed byte slice from `u`.
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(bytes_mut_from_slice(<&[u8]>::arbitrary(u)?))
+    }
+
+    /// Takes all remaining bytes from `u`.
+    #[inline]
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Ok(bytes_mut_from_slice(<&[u8]>::arbitrary_take_rest(u)?))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&[u8]>::size_hint(depth)
+    }
+}
+
+#[inline]
+fn bytes_mut_from_slice(slice: &[u8]) -> BytesMut {
+    let mut bytes_mut = BytesMut::new();
+    bytes_mut.extend_from_slice(slice);
+    bytes_mut
+}
+
+#[cfg(test)]
+mod tests {
+    use ::arbitrary::{
+        Arbitrary,
+        Unstructured,
+    };
+
+    use crate::{
+        buf::Length,
+        Bytes,
+        BytesMut,
+    };
+
+    #[test]
+    fn it_takes_rest() {
+        let bytes = Bytes::arbitrary_take_rest(Unstructured::new(b"Hello World")).unwrap();
+        assert_eq!(bytes, b"Hello World");
+
+        let bytes_mut = BytesMut::arbitrary_take_rest(Unstructured::new(b"Hello World")).unwrap();
+        assert_eq!(bytes_mut, b"Hello World");
+    }
+
+    #[test]
+    fn empty_input_produces_empty_buffer() {
+        let bytes = Bytes::arbitrary_take_rest(Unstructured::new(b"")).unwrap();
+        assert!(bytes.is_empty());
+
+        let mut u = Unstructured::new(b"");
+        assert!(Bytes::arbitrary(&mut u).unwrap().is_empty());
+        assert!(BytesMut::arbitrary(&mut u).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_matches_slice() {
+        let input = b"\x01\x02\x03\x04\x05\x06\x07\x08\x03";
+        let expected = <&[u8]>::arbitrary(&mut Unstructured::new(input)).unwrap();
+        let bytes = Bytes::arbitrary(&mut Unstructured::new(input)).unwrap();
+        assert_eq!(bytes, expected);
+    }
+}
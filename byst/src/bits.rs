@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use std::ops::{
+use core::ops::{
     BitAnd,
     BitOr,
     Shl,
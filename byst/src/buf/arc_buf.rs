@@ -1,13 +1,22 @@
-use std::{
+use alloc::boxed::Box;
+use core::{
+    alloc::Layout,
     cell::UnsafeCell,
-    fmt::Debug,
+    fmt::{
+        Debug,
+        LowerHex,
+        UpperHex,
+    },
     mem::MaybeUninit,
-    ptr::NonNull,
+    ops::Index,
     sync::atomic::{
+        fence,
         AtomicUsize,
         Ordering,
     },
 };
+#[cfg(feature = "std")]
+use std::io::IoSliceMut;
 
 use super::{
     BufReader,
@@ -30,6 +39,7 @@ use crate::{
     util::{
         buf_eq,
         debug_as_hexdump,
+        fmt_hex,
     },
     Buf,
     BufMut,
@@ -42,7 +52,7 @@ use crate::{
 
 #[derive(Clone, Copy)]
 struct Buffer {
-    /// Made from a `Box<[MaybeUninit<u8>]>>`
+    /// Allocated with the layout returned by [`Buffer::layout`].
     ///
     /// This pointer is valid as long as a [`Reclaim`] exists, or a
     /// [`BufferRef`] exists. therefore, as long as you can acquire this
@@ -55,8 +65,8 @@ struct Buffer {
 
     /// Made from a `Box<MetaData>`
     ///
-    /// Invariant: This pointer is valid as long as [`Reclaim`] exists, or
-    /// a [`BufferRef`] exists.
+    /// Invariant: This pointer is valid as long as [`Reclaim`] exists, a
+    /// [`BufferRef`] exists, or a [`WeakBuf`] exists.
     ///
     /// This may be `null` if the buffer is zero-sized. This means that no
     /// buffer was allocated for it, and thus must not be deallocated.
@@ -65,59 +75,134 @@ struct Buffer {
 
 impl Buffer {
     fn zero_sized() -> Self {
+        Self::zero_sized_aligned(1)
+    }
+
+    fn zero_sized_aligned(align: usize) -> Self {
         // special case for zero-sized buffers. they don't need to be reference counted,
-        // and use a dangling pointer for the `buf`.
+        // and use a dangling pointer for the `buf`. the dangling pointer's address is
+        // the alignment.
 
-        let buf = unsafe {
-            std::slice::from_raw_parts(
-                NonNull::<UnsafeCell<MaybeUninit<u8>>>::dangling().as_ptr(),
-                0,
-            )
-        };
+        let buf = core::ptr::slice_from_raw_parts(align as *const UnsafeCell<MaybeUninit<u8>>, 0);
 
         Self {
             buf,
-            meta_data: std::ptr::null(),
+            meta_data: core::ptr::null(),
         }
     }
 
-    fn new(size: usize, ref_count: usize, reclaim: bool) -> Self {
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    fn new(size: usize, align: usize, ref_count: usize, reclaim: bool) -> Self {
+        let layout = Self::layout(size, align);
+
         if size == 0 {
-            Self::zero_sized()
+            Self::zero_sized_aligned(layout.align())
         }
         else {
             // allocate ref_count
             let meta_data = Box::into_raw(Box::new(MetaData {
                 ref_count: AtomicRefCount::new(ref_count, reclaim),
                 initialized: UnsafeCell::new(0),
+                align,
+                weak_count: AtomicUsize::new(1),
             }));
 
             // allocate buffer
-            let buf = Box::<[u8]>::new_uninit_slice(size);
-
-            // leak it to raw pointer
-            let buf = Box::into_raw(buf);
+            let ptr = unsafe {
+                // SAFETY: `layout` has a non-zero size.
+                alloc::alloc::alloc(layout)
+            };
+            if ptr.is_null() {
+                alloc::alloc::handle_alloc_error(layout);
+            }
 
             // make it `*const [UnsafeCell<_>>]`. This is roughly what
             // `UnsafeCell::from_mut` does.
-            let buf = buf as *const [UnsafeCell<MaybeUninit<u8>>];
+            let buf =
+                core::ptr::slice_from_raw_parts(ptr as *const UnsafeCell<MaybeUninit<u8>>, size);
+
+            Buffer { buf, meta_data }
+        }
+    }
+
+    /// Adopts the allocation of `boxed`. The buffer is fully initialized.
+    fn from_boxed_slice(boxed: Box<[u8]>) -> Self {
+        let size = boxed.len();
+
+        if size == 0 {
+            Self::zero_sized()
+        }
+        else {
+            let meta_data = Box::into_raw(Box::new(MetaData {
+                ref_count: AtomicRefCount::new(1, false),
+                initialized: UnsafeCell::new(size),
+                align: 1,
+                weak_count: AtomicUsize::new(1),
+            }));
+
+            // a `Box<[u8]>` is allocated with the same layout as `Self::layout(size, 1)`,
+            // so `deallocate` can free it.
+            let buf = Box::into_raw(boxed) as *const [UnsafeCell<MaybeUninit<u8>>];
 
             Buffer { buf, meta_data }
         }
     }
 
+    fn layout(size: usize, align: usize) -> Layout {
+        Layout::from_size_align(size, align)
+            .unwrap_or_else(|_| panic!("Invalid layout for buffer: size = {size}, align = {align}"))
+    }
+
     fn len(&self) -> usize {
         self.buf.len()
     }
 
+    #[inline]
+    fn alignment(&self) -> usize {
+        if self.meta_data.is_null() {
+            // zero-sized buffers store their alignment as the address of the dangling
+            // pointer.
+            self.buf as *const u8 as usize
+        }
+        else {
+            unsafe {
+                // SAFETY: This `Buffer` only becomes invalid, if it's deallocated, but that
+                // method is unsafe.
+                (*self.meta_data).align
+            }
+        }
+    }
+
     #[inline]
     unsafe fn deallocate(self) {
         assert!(
             !self.meta_data.is_null(),
             "Trying to deallocate a zero-sized Buffer"
         );
-        let _ref_count = Box::from_raw(self.meta_data as *mut MetaData);
-        let _buf = Box::from_raw(self.buf as *mut [UnsafeCell<MaybeUninit<u8>>]);
+        alloc::alloc::dealloc(
+            self.buf as *mut u8,
+            Self::layout(self.len(), (*self.meta_data).align),
+        );
+        // release the weak reference that is collectively held by the strong
+        // references.
+        self.release_weak();
+    }
+
+    /// Decrements the weak reference count and deallocates the [`MetaData`] if
+    /// it reaches 0.
+    ///
+    /// # Safety
+    ///
+    /// The caller must own a weak reference to the [`MetaData`], which must not
+    /// be null.
+    #[inline]
+    unsafe fn release_weak(self) {
+        if (*self.meta_data).weak_count.fetch_sub(1, Ordering::Release) == 1 {
+            fence(Ordering::Acquire);
+            let _meta_data = Box::from_raw(self.meta_data as *mut MetaData);
+        }
     }
 
     #[inline]
@@ -138,60 +223,185 @@ impl Buffer {
 struct MetaData {
     ref_count: AtomicRefCount,
     initialized: UnsafeCell<usize>,
+    align: usize,
+
+    /// Number of [`WeakBuf`]s, plus one that is collectively held by all other
+    /// references. The [`MetaData`] is deallocated when this reaches 0, which
+    /// might be after the buffer itself has been deallocated.
+    weak_count: AtomicUsize,
+}
+
+impl MetaData {
+    /// Value of [`MetaData::weak_count`] while it's locked by
+    /// [`MetaData::is_unique`].
+    const WEAK_LOCKED: usize = usize::MAX;
+
+    /// Returns whether the caller holds the only strong reference, and no
+    /// [`WeakBuf`] can be upgraded.
+    ///
+    /// The caller must hold a strong reference.
+    fn is_unique(&self) -> bool {
+        let value = self.ref_count.0.load(Ordering::Acquire);
+        if value >> AtomicRefCount::SHIFT != 1 {
+            false
+        }
+        else if value & AtomicRefCount::DETACHED != 0 {
+            // `WeakBuf`s to a detached buffer can't be upgraded.
+            true
+        }
+        else if self
+            .weak_count
+            .compare_exchange(1, Self::WEAK_LOCKED, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+        {
+            // there are no `WeakBuf`s, and while the weak count is locked, no new ones can
+            // be created. so the strong count can't be incremented by anyone but us.
+            let unique = self.ref_count.outstanding_acquire() == 1;
+            self.weak_count.store(1, Ordering::Release);
+            unique
+        }
+        else {
+            false
+        }
+    }
+
+    /// Increments the weak count for a new [`WeakBuf`].
+    ///
+    /// The caller must hold a strong reference.
+    fn downgrade(&self) {
+        let mut value = self.weak_count.load(Ordering::Relaxed);
+        loop {
+            if value == Self::WEAK_LOCKED {
+                core::hint::spin_loop();
+                value = self.weak_count.load(Ordering::Relaxed);
+                continue;
+            }
+            match self.weak_count.compare_exchange_weak(
+                value,
+                value + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(current) => value = current,
+            }
+        }
+    }
 }
 
 /// This manages the reference count of a [`Buffer`]:
 ///
 /// - [`Buffer`]s can have *one* reference from a [`Reclaim`]. This is stored as
 ///   the LSB.
+/// - [`Buffer`]s that were reclaimed while [`WeakBuf`]s to them still existed
+///   are *detached*. Such [`WeakBuf`]s must not be upgraded anymore, since the
+///   buffer now belongs to a new [`ArcBufMut`]. This is stored in the second
+///   bit.
 /// - [`Buffer`]s can have any number of references through [`BufferRef`]. This
 ///   is stored in the remaining bits.
 struct AtomicRefCount(AtomicUsize);
 
 impl AtomicRefCount {
+    const RECLAIM: usize = 1;
+    const DETACHED: usize = 2;
+    const ONE: usize = 4;
+    const SHIFT: u32 = 2;
+
     #[inline]
     fn new(ref_count: usize, reclaim: bool) -> Self {
         Self(AtomicUsize::new(
-            ref_count << 1 | if reclaim { 1 } else { 0 },
+            ref_count << Self::SHIFT | if reclaim { Self::RECLAIM } else { 0 },
         ))
     }
 
     /// Increments reference count for [`BufferRef`]s
     #[inline]
     fn increment(&self) {
-        self.0.fetch_add(2, Ordering::Relaxed);
+        self.0.fetch_add(Self::ONE, Ordering::Relaxed);
     }
 
     /// Decrements reference count for [`BufferRef`]s and returns whether the
     /// buffer must be deallocated.
     #[inline]
     fn decrement(&self) -> MustDrop {
-        let old_value = self.0.fetch_sub(2, Ordering::Relaxed);
-        assert!(old_value >= 2);
-        MustDrop(old_value == 2)
+        let old_value = self.0.fetch_sub(Self::ONE, Ordering::Relaxed);
+        assert!(old_value >= Self::ONE);
+        MustDrop(old_value & !Self::DETACHED == Self::ONE)
     }
 
     /// Removes the [`Reclaim`] reference and returns whether the buffer must be
     /// deallocated.
     #[inline]
     fn make_unreclaimable(&self) -> MustDrop {
-        MustDrop(self.0.fetch_and(!1, Ordering::Relaxed) == 1)
+        let old_value = self.0.fetch_and(!Self::RECLAIM, Ordering::Relaxed);
+        MustDrop(old_value & !Self::DETACHED == Self::RECLAIM)
     }
 
     /// Trys to reclaim the buffer. This will only be successful if the
     /// reclaim-reference is the only one to the buffer. In this case it'll
-    /// increase the normal ref-count and return `true`.
+    /// increase the normal ref-count and return `Ok`. Otherwise it returns the
+    /// reference count for [`BufferRef`]s.
+    ///
+    /// If `detach` is `true`, the buffer is marked as detached, so that
+    /// existing [`WeakBuf`]s can't be upgraded anymore. Otherwise the mark is
+    /// cleared.
     #[inline]
-    fn try_reclaim(&self) -> bool {
+    fn try_reclaim_or_count(&self, detach: bool) -> Result<(), usize> {
+        let new_value = Self::ONE | Self::RECLAIM | if detach { Self::DETACHED } else { 0 };
         self.0
-            .compare_exchange(1, 3, Ordering::Relaxed, Ordering::Relaxed)
-            .is_ok()
+            .fetch_update(Ordering::Acquire, Ordering::Relaxed, |value| {
+                (value & !Self::DETACHED == Self::RECLAIM).then_some(new_value)
+            })
+            .map(|_| ())
+            .map_err(|value| value >> Self::SHIFT)
+    }
+
+    /// Returns the reference count for [`BufferRef`]s.
+    #[inline]
+    fn outstanding(&self) -> usize {
+        self.0.load(Ordering::Relaxed) >> Self::SHIFT
+    }
+
+    /// Returns the reference count for [`BufferRef`]s, synchronizing with
+    /// [`AtomicRefCount::try_increment`].
+    #[inline]
+    fn outstanding_acquire(&self) -> usize {
+        self.0.load(Ordering::Acquire) >> Self::SHIFT
+    }
+
+    /// Increments reference count for [`BufferRef`]s, unless it's 0 or the
+    /// buffer is detached. Returns whether the reference count was
+    /// incremented.
+    #[inline]
+    fn try_increment(&self) -> bool {
+        let mut value = self.0.load(Ordering::Relaxed);
+        loop {
+            if value >> Self::SHIFT == 0 || value & Self::DETACHED != 0 {
+                break false;
+            }
+            match self.0.compare_exchange_weak(
+                value,
+                value + Self::ONE,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break true,
+                Err(current) => value = current,
+            }
+        }
+    }
+
+    /// Adds the [`Reclaim`] reference and returns whether it was added. This
+    /// fails if the buffer already has a [`Reclaim`] reference.
+    #[inline]
+    fn try_make_reclaimable(&self) -> bool {
+        self.0.fetch_or(Self::RECLAIM, Ordering::Relaxed) & Self::RECLAIM == 0
     }
 
     /// Checks if the buffer can be reclaimed.
     #[inline]
     fn can_reclaim(&self) -> bool {
-        self.0.load(Ordering::Relaxed) == 1
+        self.0.load(Ordering::Relaxed) & !Self::DETACHED == Self::RECLAIM
     }
 }
 
@@ -214,10 +424,10 @@ pub enum RefCount {
 impl RefCount {
     fn from_atomic(value: &AtomicRefCount) -> Self {
         let value = value.0.load(Ordering::Relaxed);
-        let ref_count = value >> 1;
+        let ref_count = value >> AtomicRefCount::SHIFT;
         Self::Counted {
             ref_count,
-            reclaim: value & 1 != 0,
+            reclaim: value & AtomicRefCount::RECLAIM != 0,
         }
     }
 
@@ -241,6 +451,16 @@ impl RefCount {
     pub fn is_static(&self) -> bool {
         matches!(self, Self::Static)
     }
+
+    /// Returns `true` if there is exactly one counted reference to the buffer.
+    ///
+    /// The [`Reclaim`] reference isn't counted. Static buffers are never
+    /// unique. This doesn't consider [`WeakBuf`]s, so it doesn't mean that the
+    /// buffer can be mutated in place. Use [`ArcBuf::is_unique`] for that.
+    #[inline]
+    pub fn is_unique(&self) -> bool {
+        matches!(self, Self::Counted { ref_count: 1, .. })
+    }
 }
 
 struct BufferRef {
@@ -286,7 +506,7 @@ impl BufferRef {
         // - `Buffer` is valid, since we have a `BufferRef` to it.
         // - The range is valid
         let ptr = self.buf.buf.get_unchecked(self.start..self.end);
-        std::slice::from_raw_parts(UnsafeCell::raw_get(ptr.as_ptr()), self.end - self.start)
+        core::slice::from_raw_parts(UnsafeCell::raw_get(ptr.as_ptr()), self.end - self.start)
     }
 
     /// # Safety
@@ -301,7 +521,7 @@ impl BufferRef {
         // - `Buffer` is valid, since we have a `BufferRef` to it.
         // - The range is valid
         let ptr = self.buf.buf.get_unchecked(self.start..self.end);
-        std::slice::from_raw_parts_mut(UnsafeCell::raw_get(ptr.as_ptr()), self.end - self.start)
+        core::slice::from_raw_parts_mut(UnsafeCell::raw_get(ptr.as_ptr()), self.end - self.start)
     }
 
     /// # Safety
@@ -359,7 +579,7 @@ impl BufferRef {
                     "BufferRef is tail, but initialized is out of its bounds."
                 );
 
-                *initialized = std::cmp::max(*initialized, to);
+                *initialized = core::cmp::max(*initialized, to);
             }
         }
         else {
@@ -381,8 +601,10 @@ impl BufferRef {
         // - The range is valid
         // - The range is initialized
         let ptr = self.buf.buf.get_unchecked(self.start..initialized);
-        let slice =
-            std::slice::from_raw_parts(UnsafeCell::raw_get(ptr.as_ptr()), initialized - self.start);
+        let slice = core::slice::from_raw_parts(
+            UnsafeCell::raw_get(ptr.as_ptr()),
+            initialized - self.start,
+        );
         MaybeUninit::slice_assume_init_ref(slice)
     }
 
@@ -400,7 +622,7 @@ impl BufferRef {
         // - The range is valid
         // - The range is initialized
         let ptr = self.buf.buf.get_unchecked(self.start..initialized);
-        let slice = std::slice::from_raw_parts_mut(
+        let slice = core::slice::from_raw_parts_mut(
             UnsafeCell::raw_get(ptr.as_ptr()),
             initialized - self.start,
         );
@@ -421,11 +643,11 @@ impl BufferRef {
 
         assert!(split_offset <= self.end);
 
-        if at == self.start {
+        if at == 0 {
             Self::default()
         }
-        else if at == self.end {
-            std::mem::take(self)
+        else if split_offset == self.end {
+            core::mem::take(self)
         }
         else {
             let mut new = self.clone();
@@ -481,7 +703,7 @@ impl BufferRef {
                 );
 
                 let ptr = self.buf.buf.get_unchecked(*initialized..self.end);
-                let slice = std::slice::from_raw_parts_mut(
+                let slice = core::slice::from_raw_parts_mut(
                     UnsafeCell::raw_get(ptr.as_ptr()),
                     self.end - *initialized,
                 );
@@ -546,18 +768,32 @@ pub struct Reclaim {
 }
 
 impl Reclaim {
+    #[inline]
     pub fn try_reclaim(&self) -> Option<ArcBufMut> {
+        self.try_reclaim_or_count().ok()
+    }
+
+    /// Trys to reclaim the buffer, like [`try_reclaim`](Self::try_reclaim).
+    ///
+    /// On failure this returns the number of outstanding references to the
+    /// buffer, e.g. to report backpressure of a buffer pool.
+    pub fn try_reclaim_or_count(&self) -> Result<ArcBufMut, usize> {
         if self.buf.meta_data.is_null() {
-            Some(ArcBufMut::default())
+            Ok(ArcBufMut::default())
         }
         else {
             let reclaimed = unsafe {
                 // SAFETY: We have a [`Reclaim`] reference to the buffer, so it hasn't been
                 // deallocated. Thus it's safe to dereference the `ref_count`.
-                (*self.buf.meta_data).ref_count.try_reclaim()
+                let meta_data = &*self.buf.meta_data;
+                // while the buffer can be reclaimed, there are no strong references that
+                // could create new `WeakBuf`s. if there are `WeakBuf`s left, they must not
+                // be upgraded to the reclaimed buffer.
+                let detach = meta_data.weak_count.load(Ordering::Acquire) != 1;
+                meta_data.ref_count.try_reclaim_or_count(detach)
             };
 
-            reclaimed.then(|| {
+            reclaimed.map(|()| {
                 // we reclaimed the buffer, thus we can hand out a new unique reference to it :)
                 ArcBufMut {
                     inner: BufferRef {
@@ -567,11 +803,40 @@ impl Reclaim {
                         tail: true,
                     },
                     filled: 0,
+                    headroom: 0,
                 }
             })
         }
     }
 
+    /// Trys to reclaim the buffer, like [`try_reclaim`](Self::try_reclaim),
+    /// and zeroizes it.
+    ///
+    /// See [`ArcBufMut::zeroize`].
+    #[cfg(feature = "zeroize")]
+    #[inline]
+    pub fn try_reclaim_zeroized(&self) -> Option<ArcBufMut> {
+        let mut buf = self.try_reclaim()?;
+        buf.zeroize();
+        Some(buf)
+    }
+
+    /// Returns the number of outstanding references to the buffer, not
+    /// counting the [`Reclaim`] itself.
+    #[inline]
+    pub fn outstanding(&self) -> usize {
+        if self.buf.meta_data.is_null() {
+            0
+        }
+        else {
+            unsafe {
+                // SAFETY: We have a [`Reclaim`] reference to the buffer, so it hasn't been
+                // deallocated. Thus it's safe to dereference the `ref_count`.
+                (*self.buf.meta_data).ref_count.outstanding()
+            }
+        }
+    }
+
     #[inline]
     pub fn can_reclaim(&self) -> bool {
         if self.buf.meta_data.is_null() {
@@ -610,7 +875,7 @@ impl Drop for Reclaim {
 }
 
 impl Debug for Reclaim {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Reclaim").finish_non_exhaustive()
     }
 }
@@ -644,6 +909,69 @@ impl ArcBuf {
     pub fn ref_count(&self) -> RefCount {
         self.inner.ref_count()
     }
+
+    /// Returns `true` if this is the only reference to the underlying buffer,
+    /// and there are no [`WeakBuf`]s that could be upgraded.
+    ///
+    /// In this case the buffer can be converted back into an [`ArcBufMut`]
+    /// with [`try_into_mut`](Self::try_into_mut). This is stricter than
+    /// [`RefCount::is_unique`], which doesn't consider [`WeakBuf`]s.
+    #[inline]
+    pub fn is_unique(&self) -> bool {
+        if self.inner.buf.meta_data.is_null() {
+            false
+        }
+        else {
+            unsafe {
+                // SAFETY: We have a [`BufferRef`] to the buffer, so the `meta_data` is
+                // valid.
+                (*self.inner.buf.meta_data).is_unique()
+            }
+        }
+    }
+
+    /// Converts into an [`ArcBufMut`] without copying, if this is the only
+    /// reference to the underlying buffer.
+    ///
+    /// The returned [`ArcBufMut`] is filled with the bytes of `self`, and has
+    /// no spare capacity. If there are other references to the buffer, or
+    /// it's not reference-counted, `self` is returned.
+    pub fn try_into_mut(self) -> Result<ArcBufMut, ArcBuf> {
+        if !self.is_unique() {
+            return Err(self);
+        }
+
+        let mut inner = self.inner;
+        // the bytes of an `ArcBuf` are fully initialized, so the new `ArcBufMut`
+        // doesn't need to track the initialized portion.
+        inner.tail = false;
+        let filled = inner.len();
+        Ok(ArcBufMut {
+            inner,
+            filled,
+            headroom: 0,
+        })
+    }
+
+    /// Creates a [`WeakBuf`] to this buffer.
+    ///
+    /// The [`WeakBuf`] doesn't keep the buffer alive, but can be upgraded to an
+    /// [`ArcBuf`] as long as there are other references to it.
+    pub fn downgrade(&self) -> WeakBuf {
+        if !self.inner.buf.meta_data.is_null() {
+            unsafe {
+                // SAFETY: We have a [`BufferRef`] to the buffer, so the `meta_data` is
+                // valid.
+                (*self.inner.buf.meta_data).downgrade();
+            }
+        }
+
+        WeakBuf {
+            buf: self.inner.buf,
+            start: self.inner.start,
+            end: self.inner.end,
+        }
+    }
 }
 
 impl Buf for ArcBuf {
@@ -666,6 +994,11 @@ impl Buf for ArcBuf {
     fn reader(&self) -> Self::Reader<'_> {
         Clone::clone(self)
     }
+
+    #[inline]
+    fn to_bytes(&self) -> Bytes {
+        Clone::clone(self).into()
+    }
 }
 
 impl BufReader for ArcBuf {
@@ -708,7 +1041,7 @@ impl BufReader for ArcBuf {
 
     #[inline]
     fn rest(&mut self) -> Self::View {
-        std::mem::take(self)
+        core::mem::take(self)
     }
 
     #[inline]
@@ -747,7 +1080,7 @@ impl Seek for ArcBuf {
 
     #[inline]
     fn seek(&mut self, position: &Self::Position) -> Self::Position {
-        std::mem::replace(self, Clone::clone(position))
+        core::mem::replace(self, Clone::clone(position))
     }
 }
 
@@ -767,6 +1100,23 @@ impl<'b> BytesImpl<'b> for ArcBuf {
     fn advance(&mut self, by: usize) -> Result<(), End> {
         BufReader::advance(self, by)
     }
+
+    fn ref_count(&self) -> RefCount {
+        ArcBuf::ref_count(self)
+    }
+
+    fn is_unique(&self) -> bool {
+        ArcBuf::is_unique(self)
+    }
+
+    fn try_into_mut(
+        self: Box<Self>,
+    ) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, Box<dyn BytesImpl<'b> + 'b>> {
+        match ArcBuf::try_into_mut(*self) {
+            Ok(buf) => Ok(Box::new(buf)),
+            Err(buf) => Err(Box::new(buf)),
+        }
+    }
 }
 
 impl Length for ArcBuf {
@@ -785,11 +1135,47 @@ impl AsRef<[u8]> for ArcBuf {
 
 impl Debug for ArcBuf {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         debug_as_hexdump(f, self.bytes())
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl ::zeroize::Zeroize for ArcBufMut {
+    #[inline]
+    fn zeroize(&mut self) {
+        ArcBufMut::zeroize(self);
+    }
+}
+
+impl Index<usize> for ArcBuf {
+    type Output = u8;
+
+    /// Returns the byte at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        &self.bytes()[index]
+    }
+}
+
+impl LowerHex for ArcBuf {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_hex(f, self.bytes(), false)
+    }
+}
+
+impl UpperHex for ArcBuf {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_hex(f, self.bytes(), true)
+    }
+}
+
 impl<T: Buf> PartialEq<T> for ArcBuf {
     #[inline]
     fn eq(&self, other: &T) -> bool {
@@ -804,10 +1190,102 @@ impl<T: Buf> PartialEq<T> for ArcBuf {
 unsafe impl Send for ArcBuf {}
 unsafe impl Sync for ArcBuf {}
 
+/// A weak reference to an [`ArcBuf`].
+///
+/// This doesn't keep the underlying buffer alive. It's created with
+/// [`ArcBuf::downgrade`].
+///
+/// If the underlying buffer is reclaimed with a [`Reclaim`] handle while
+/// [`WeakBuf`]s to it exist, they can't be upgraded anymore, even though the
+/// buffer is alive again.
+pub struct WeakBuf {
+    /// Invariant: `meta_data` is valid as long as this [`WeakBuf`] exists, but
+    /// `buf` might have been deallocated.
+    buf: Buffer,
+    start: usize,
+    end: usize,
+}
+
+impl WeakBuf {
+    /// Tries to upgrade this to an [`ArcBuf`]. Returns `None` if the buffer has
+    /// already been dropped.
+    pub fn upgrade(&self) -> Option<ArcBuf> {
+        if self.buf.meta_data.is_null() {
+            Some(ArcBuf::default())
+        }
+        else {
+            let upgraded = unsafe {
+                // SAFETY: We have a weak reference, so the `meta_data` is valid.
+                (*self.buf.meta_data).ref_count.try_increment()
+            };
+
+            // we acquired a strong reference, so the buffer is valid.
+            upgraded.then(|| {
+                ArcBuf {
+                    inner: BufferRef {
+                        buf: self.buf,
+                        start: self.start,
+                        end: self.end,
+                        tail: false,
+                    },
+                }
+            })
+        }
+    }
+}
+
+impl Clone for WeakBuf {
+    fn clone(&self) -> Self {
+        if !self.buf.meta_data.is_null() {
+            unsafe {
+                // SAFETY: We have a weak reference, so the `meta_data` is valid.
+                (*self.buf.meta_data)
+                    .weak_count
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Self {
+            buf: self.buf,
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl Drop for WeakBuf {
+    fn drop(&mut self) {
+        if !self.buf.meta_data.is_null() {
+            unsafe {
+                // SAFETY: We own a weak reference that we release here.
+                self.buf.release_weak();
+            }
+        }
+    }
+}
+
+impl Debug for WeakBuf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WeakBuf").finish_non_exhaustive()
+    }
+}
+
+// SAFETY:
+//
+// This is safe to impl `Send` and `Sync`, because all it ever does is access
+// the meta data / ref count through a `*const MetaData`
+unsafe impl Send for WeakBuf {}
+unsafe impl Sync for WeakBuf {}
+
 #[derive(Default)]
 pub struct ArcBufMut {
     inner: BufferRef,
     filled: usize,
+
+    /// Number of bytes before `inner.start` that are owned by this buffer and
+    /// can be used by [`ArcBufMut::prepend`]. These bytes are always
+    /// initialized.
+    headroom: usize,
 }
 
 impl ArcBufMut {
@@ -818,16 +1296,56 @@ impl ArcBufMut {
         Self {
             inner: unsafe { BufferRef::from_buf(buf) },
             filled: 0,
+            headroom: 0,
         }
     }
 
     /// Creates a new [`ArcBufMut`] with the specified capacity.
     #[inline]
     pub fn new(capacity: usize) -> Self {
-        let buf = Buffer::new(capacity, 1, false);
+        Self::with_alignment(capacity, 1)
+    }
+
+    /// Creates a new [`ArcBufMut`] with the specified capacity, whose buffer
+    /// starts at an address that is a multiple of `align`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    #[inline]
+    pub fn with_alignment(capacity: usize, align: usize) -> Self {
+        let buf = Buffer::new(capacity, align, 1, false);
         unsafe { Self::from_buffer(buf) }
     }
 
+    /// Creates a new [`ArcBufMut`] with the specified capacity, and `headroom`
+    /// bytes of free space before it.
+    ///
+    /// The headroom can be filled with [`prepend`](Self::prepend), e.g. to add
+    /// a header in front of a payload that has already been written, without
+    /// copying the payload.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity + headroom` overflows.
+    pub fn with_headroom(capacity: usize, headroom: usize) -> Self {
+        let total = capacity
+            .checked_add(headroom)
+            .expect("ArcBufMut::with_headroom: capacity overflow");
+        let mut this = Self::new(total);
+        if headroom > 0 {
+            unsafe {
+                // SAFETY: We're only writing initialized bytes, and we initialized the
+                // headroom before marking it as such.
+                MaybeUninit::fill(&mut this.uninitialized_mut()[..headroom], 0);
+                this.set_initialized_to(headroom);
+            }
+            this.inner.start = headroom;
+            this.headroom = headroom;
+        }
+        this
+    }
+
     /// Creates a new [`ArcBufMut`], with a handle to reclaim it.
     ///
     /// A reclaimable buffer will not be freed when all ordinary references
@@ -857,7 +1375,7 @@ impl ArcBufMut {
     /// ```
     #[inline]
     pub fn new_reclaimable(capacity: usize) -> (Self, Reclaim) {
-        let buf = Buffer::new(capacity, 1, true);
+        let buf = Buffer::new(capacity, 1, 1, true);
         let this = unsafe { Self::from_buffer(buf) };
         let reclaim = Reclaim { buf };
         (this, reclaim)
@@ -869,6 +1387,51 @@ impl ArcBufMut {
         self.inner.len()
     }
 
+    /// Returns the number of bytes that can still be prepended to this buffer.
+    ///
+    /// See [`with_headroom`](Self::with_headroom).
+    #[inline]
+    pub fn headroom(&self) -> usize {
+        self.headroom
+    }
+
+    /// Writes `data` into the headroom in front of the filled portion.
+    ///
+    /// This doesn't copy the filled portion. If there isn't enough headroom
+    /// left, this fails with [`Full`] and nothing is written. See
+    /// [`with_headroom`](Self::with_headroom).
+    pub fn prepend(&mut self, data: &[u8]) -> Result<(), Full> {
+        if data.len() > self.headroom {
+            return Err(Full {
+                required: data.len(),
+                capacity: self.headroom,
+            });
+        }
+
+        // the headroom is initialized and directly precedes `inner.start`, so this
+        // keeps the filled portion initialized.
+        self.headroom -= data.len();
+        self.inner.start -= data.len();
+        self.filled += data.len();
+        self.filled_mut()[..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Returns the alignment of the start of this buffer.
+    ///
+    /// This is the alignment the buffer was allocated with, unless this
+    /// [`ArcBufMut`] was split off at an offset that is less aligned.
+    #[inline]
+    pub fn alignment(&self) -> usize {
+        let align = self.inner.buf.alignment();
+        if self.inner.start == 0 {
+            align
+        }
+        else {
+            core::cmp::min(align, 1 << self.inner.start.trailing_zeros())
+        }
+    }
+
     /// Makes the buffer immutable.
     ///
     /// This returns an [`ArcBuf`] that can be cheaply cloned and shared.
@@ -881,6 +1444,29 @@ impl ArcBufMut {
         ArcBuf { inner: self.inner }
     }
 
+    /// Makes the buffer immutable, like [`freeze`](Self::freeze), and returns
+    /// a handle to reclaim it.
+    ///
+    /// Once all [`ArcBuf`]s (and other references) to the underlying buffer
+    /// are dropped, it can be reclaimed as a fresh [`ArcBufMut`] using the
+    /// [`Reclaim`] handle. See [`ArcBufMut::new_reclaimable`].
+    ///
+    /// This fails and returns `self`, if the underlying buffer already has a
+    /// [`Reclaim`] handle.
+    pub fn freeze_reclaimable(self) -> Result<(ArcBuf, Reclaim), Self> {
+        let buf = self.inner.buf;
+        if !buf.meta_data.is_null() {
+            let added = unsafe {
+                // SAFETY: As long as there is a [`BufferRef`], the [`Buffer`] is valid.
+                (*buf.meta_data).ref_count.try_make_reclaimable()
+            };
+            if !added {
+                return Err(self);
+            }
+        }
+        Ok((self.freeze(), Reclaim { buf }))
+    }
+
     /// Returns the reference count for this buffer.
     ///
     /// This includes all references to the underlying buffer, even if it was
@@ -890,22 +1476,37 @@ impl ArcBufMut {
         self.inner.ref_count()
     }
 
-    /// Splits `self` into:
+    /// Returns a [`Writer`] that starts writing at `offset`.
     ///
-    /// 1. `self`: Right half starting with `at`. (`[at..]`)
-    /// 2. returns: Left half up to `at`, but not including it. (`[..at)`)
+    /// This is useful to patch bytes that have already been written, e.g. a
+    /// length prefix. See [`Writer::set_position`].
+    #[inline]
+    pub fn writer_at(&mut self, offset: usize) -> Result<Writer<'_>, IndexOutOfBounds> {
+        let mut writer = Writer::new(self);
+        writer.set_position(offset)?;
+        Ok(writer)
+    }
+
+    /// Splits `self` into:
+    ///
+    /// 1. `self`: Right half starting with `at`. (`[at..]`)
+    /// 2. returns: Left half up to `at`, but not including it. (`[..at)`)
     pub fn split_at(&mut self, at: usize) -> Result<ArcBufMut, IndexOutOfBounds> {
         let filled = self.filled;
         if at == 0 {
             Ok(Self::default())
         }
-        else if at == filled {
-            Ok(std::mem::take(self))
-        }
-        else if at < filled {
+        else if at <= filled {
+            // note: if `at == filled`, `self` keeps the unfilled tail of the buffer, so
+            // it can still be written to.
             let inner = self.inner.split_at(at);
             self.filled = filled - at;
-            Ok(Self { inner, filled: at })
+            let headroom = core::mem::take(&mut self.headroom);
+            Ok(Self {
+                inner,
+                filled: at,
+                headroom,
+            })
         }
         else {
             Err(IndexOutOfBounds {
@@ -915,6 +1516,58 @@ impl ArcBufMut {
         }
     }
 
+    /// Splits `self` into:
+    ///
+    /// 1. `self`: Left half up to `at`, but not including it. (`[..at)`)
+    /// 2. returns: Right half starting with `at`. (`[at..]`)
+    ///
+    /// This is the complement of [`split_at`](Self::split_at). The returned
+    /// half keeps the unfilled tail of the buffer, so it can still be written
+    /// to.
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Result<ArcBufMut, IndexOutOfBounds> {
+        let left = self.split_at(at)?;
+        Ok(core::mem::replace(self, left))
+    }
+
+    /// Joins `other` back onto the end of `self`, if they were split from the
+    /// same buffer and are adjacent.
+    ///
+    /// This is the inverse of [`split_off`](Self::split_off) and doesn't copy.
+    /// It requires that `self` is filled up to its end, i.e. that there's no
+    /// unfilled gap between the two halves. Otherwise `other` is returned.
+    ///
+    /// If either buffer has no capacity, this trivially succeeds.
+    pub fn try_unsplit(&mut self, other: ArcBufMut) -> Result<(), ArcBufMut> {
+        if other.capacity() == 0 {
+            return Ok(());
+        }
+        if self.capacity() == 0 && self.headroom == 0 {
+            *self = other;
+            return Ok(());
+        }
+
+        let adjacent = !self.inner.buf.meta_data.is_null()
+            && core::ptr::eq(self.inner.buf.meta_data, other.inner.buf.meta_data)
+            && self.inner.end == other.inner.start
+            && self.filled == self.inner.len();
+        if !adjacent {
+            return Err(other);
+        }
+
+        // `self` is fully initialized, since it's filled up to its end. If `other` is
+        // the tail, the merged buffer becomes the tail and tracks the initialized
+        // portion, which lies within `other`.
+        self.inner.end = other.inner.end;
+        self.inner.tail = other.inner.tail;
+        self.filled += other.filled;
+
+        // dropping `other` releases its reference to the buffer. `self` still holds
+        // one, so this won't deallocate.
+        drop(other);
+        Ok(())
+    }
+
     /// Returns an immutable reference to the filled portion of the buffer.
     #[inline]
     fn filled(&self) -> &[u8] {
@@ -1086,6 +1739,131 @@ impl ArcBufMut {
     pub fn clear(&mut self) {
         self.filled = 0;
     }
+
+    /// Shortens the buffer to the first `len` bytes.
+    ///
+    /// If `len` is greater or equal to the current length, this has no effect.
+    /// Like [`clear`](Self::clear), this keeps the truncated bytes
+    /// initialized, and subsequent writes will overwrite them.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.filled = self.filled.min(len);
+    }
+
+    /// Overwrites the initialized portion of the buffer with zeros, and
+    /// clears it.
+    ///
+    /// The writes are volatile, so they can't be optimized away, even if the
+    /// buffer isn't read afterwards. Use this to clear buffers that held
+    /// secrets.
+    #[cfg(feature = "zeroize")]
+    #[inline]
+    pub fn zeroize(&mut self) {
+        ::zeroize::Zeroize::zeroize(self.initialized_mut());
+        self.clear();
+    }
+
+    /// Returns the capacity that a buffer with `capacity` grows to, so that it
+    /// fits at least `required` bytes.
+    ///
+    /// The capacity at least doubles, so that repeated appends take amortized
+    /// linear time.
+    #[inline]
+    pub(crate) fn grown_capacity(capacity: usize, required: usize) -> usize {
+        required.max(capacity.saturating_mul(2))
+    }
+
+    /// Grows the buffer to at least `capacity`, by allocating a new buffer
+    /// and copying the filled portion into it.
+    ///
+    /// This does nothing if the buffer already has enough capacity. See
+    /// [`grown_capacity`](Self::grown_capacity) for how much it grows. The new
+    /// buffer has the same [`headroom`](Self::headroom).
+    pub(crate) fn grow_to(&mut self, capacity: usize) {
+        if capacity > self.capacity() {
+            let mut grown = Self::with_headroom(
+                Self::grown_capacity(self.capacity(), capacity),
+                self.headroom,
+            );
+            BufWriter::extend(&mut Writer::new(&mut grown), self.filled())
+                .expect("Newly allocated buffer is too small");
+            *self = grown;
+        }
+    }
+}
+
+impl From<Box<[u8]>> for ArcBufMut {
+    /// Adopts the allocation of the boxed slice, without copying.
+    ///
+    /// The resulting buffer is filled with the slice's contents.
+    #[inline]
+    fn from(value: Box<[u8]>) -> Self {
+        let filled = value.len();
+        let buf = Buffer::from_boxed_slice(value);
+        let mut this = unsafe {
+            // SAFETY: We just created the buffer.
+            Self::from_buffer(buf)
+        };
+        this.filled = filled;
+        this
+    }
+}
+
+impl Extend<u8> for ArcBufMut {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.grow_to(self.filled + lower);
+
+        for byte in iter {
+            if self.filled == self.capacity() {
+                self.grow_to(core::cmp::max(self.filled + 1, 8));
+            }
+
+            unsafe {
+                // SAFETY:
+                //
+                // - We have the only mutable reference to that portion of the buffer.
+                // - `self.filled < self.capacity()`, since we grew the buffer otherwise.
+                // - We initialize the byte before marking it as initialized.
+                self.inner.uninitialized_mut()[self.filled].write(byte);
+                self.inner.set_initialized_to(self.filled + 1);
+            }
+            self.filled += 1;
+        }
+    }
+}
+
+impl<'a> Extend<&'a u8> for ArcBufMut {
+    #[inline]
+    fn extend<T: IntoIterator<Item = &'a u8>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl core::fmt::Write for ArcBufMut {
+    /// Appends the UTF-8 bytes of `s` to the buffer.
+    ///
+    /// This fails with [`core::fmt::Error`], if the buffer doesn't have enough
+    /// capacity left for `s`. In this case nothing is written.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.put(s.as_bytes()).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl FromIterator<u8> for ArcBufMut {
+    /// Collects the bytes into a new [`ArcBufMut`].
+    ///
+    /// This uses the iterator's size hint to preallocate the buffer. If the
+    /// iterator is empty, this doesn't allocate.
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let iter = iter.into_iter();
+        let mut buf = Self::new(iter.size_hint().0);
+        buf.extend(iter);
+        buf
+    }
 }
 
 impl AsRef<[u8]> for ArcBufMut {
@@ -1104,7 +1882,7 @@ impl AsMut<[u8]> for ArcBufMut {
 
 impl Debug for ArcBufMut {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         debug_as_hexdump(f, self.filled())
     }
 }
@@ -1179,14 +1957,37 @@ impl BufMut for ArcBufMut {
     fn size_limit(&self) -> SizeLimit {
         SizeLimit::Exact(self.capacity())
     }
+
+    /// Exposes the spare capacity as a single [`IoSliceMut`].
+    ///
+    /// This fully initializes the buffer (see
+    /// [`fully_initialize`](Self::fully_initialize)) first, which is only
+    /// costly the first time.
+    #[cfg(feature = "std")]
+    fn io_slices_mut<'a>(&'a mut self, dst: &mut [IoSliceMut<'a>]) -> usize {
+        let filled = self.filled;
+        if filled == self.capacity() {
+            return 0;
+        }
+        let Some(slot) = dst.first_mut()
+        else {
+            return 0;
+        };
+        self.fully_initialize();
+        *slot = IoSliceMut::new(&mut self.initialized_mut()[filled..]);
+        1
+    }
 }
 
-impl BytesMutImpl for ArcBufMut {
+impl<'b> BytesMutImpl<'b> for ArcBufMut {
     fn view(&self, range: Range) -> Result<Box<dyn BytesImpl + '_>, RangeOutOfBounds> {
         Ok(Box::new(range.slice_get(self.filled())?))
     }
 
-    fn view_mut(&mut self, range: Range) -> Result<Box<dyn BytesMutImpl + '_>, RangeOutOfBounds> {
+    fn view_mut(
+        &mut self,
+        range: Range,
+    ) -> Result<Box<dyn BytesMutImpl<'_> + '_>, RangeOutOfBounds> {
         Ok(Box::new(BufMut::view_mut(self, range)?))
     }
 
@@ -1206,9 +2007,32 @@ impl BytesMutImpl for ArcBufMut {
         BufMut::size_limit(self)
     }
 
-    fn split_at(&mut self, at: usize) -> Result<Box<dyn BytesMutImpl + '_>, IndexOutOfBounds> {
+    fn split_at(&mut self, at: usize) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, IndexOutOfBounds> {
         Ok(Box::new(ArcBufMut::split_at(self, at)?))
     }
+
+    fn freeze(self: Box<Self>) -> Box<dyn BytesImpl<'b> + 'b> {
+        Box::new(ArcBufMut::freeze(*self))
+    }
+
+    fn into_arc_buf_mut(self: Box<Self>) -> Result<ArcBufMut, Box<dyn BytesMutImpl<'b> + 'b>> {
+        Ok(*self)
+    }
+
+    #[cfg(feature = "std")]
+    fn io_slices_mut<'a>(&'a mut self, dst: &mut [IoSliceMut<'a>]) -> usize {
+        BufMut::io_slices_mut(self, dst)
+    }
+
+    fn set_filled_to(&mut self, to: usize) {
+        ArcBufMut::set_filled_to(self, to);
+    }
+
+    fn spare_capacity_mut(&mut self) -> &mut [u8] {
+        self.fully_initialize();
+        let filled = self.filled;
+        &mut self.initialized_mut()[filled..]
+    }
 }
 
 impl From<ArcBuf> for Bytes {
@@ -1242,6 +2066,31 @@ impl<'a> Writer<'a> {
         Self { buf, position: 0 }
     }
 
+    /// Returns the position of the write cursor.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves the write cursor to `position`.
+    ///
+    /// `position` must be within the filled portion of the buffer. Writes
+    /// overwrite the filled bytes in place, and only extend the buffer once
+    /// they go past its end.
+    #[inline]
+    pub fn set_position(&mut self, position: usize) -> Result<(), IndexOutOfBounds> {
+        if position <= self.buf.filled {
+            self.position = position;
+            Ok(())
+        }
+        else {
+            Err(IndexOutOfBounds {
+                required: position,
+                bounds: (0, self.buf.filled),
+            })
+        }
+    }
+
     /// Fills the next `length` bytes by applying the closure `f` to it.
     ///
     /// # Safety
@@ -1265,7 +2114,7 @@ impl<'a> Writer<'a> {
                 self.buf.set_initialized_to(end);
             }
 
-            self.buf.filled = std::cmp::max(self.buf.filled, end);
+            self.buf.filled = core::cmp::max(self.buf.filled, end);
             self.position = end;
 
             Ok(())
@@ -1371,6 +2220,37 @@ impl<'b> BufWriter for Writer<'b> {
             .map_err(Into::into)
         }
     }
+
+    #[inline]
+    fn fill(&mut self, byte: u8, count: usize) -> Result<(), crate::io::Full> {
+        unsafe {
+            // SAFETY: The closure initializes the whole slice.
+            self.fill_with(count, |buf| {
+                MaybeUninit::fill(buf, byte);
+            })
+            .map_err(Into::into)
+        }
+    }
+
+    /// Writes all slices in `bufs` in order.
+    ///
+    /// This either writes all slices, or nothing at all, if the buffer doesn't
+    /// have enough capacity.
+    fn write_all_vectored(&mut self, bufs: &[&[u8]]) -> Result<(), crate::io::Full> {
+        let length = bufs.iter().map(|buf| buf.len()).sum();
+        unsafe {
+            // SAFETY: The slices' lengths sum up to `length`, so the closure
+            // initializes the whole slice.
+            self.fill_with(length, |mut dest| {
+                for buf in bufs {
+                    let (head, tail) = dest.split_at_mut(buf.len());
+                    MaybeUninit::copy_from_slice(head, buf);
+                    dest = tail;
+                }
+            })
+            .map_err(Into::into)
+        }
+    }
 }
 
 impl<'b> WriterImpl for Writer<'b> {
@@ -1417,11 +2297,312 @@ mod tests {
             Length,
         },
         copy,
+        endianness::NetworkEndian,
         hexdump::Hexdump,
+        io::{
+            BufWriter,
+            Writer,
+            WriterExt,
+        },
+        Buf,
+        BufMut,
+        Bytes,
     };
 
     buf_mut_tests!(ArcBufMut::new(20));
 
+    #[test]
+    fn frozen_buf_is_unique_until_cloned() {
+        let (mut buf, reclaim) = ArcBufMut::new_reclaimable(8);
+        buf.put(b"Hello" as &[u8]).unwrap();
+        let buf = buf.freeze();
+        assert!(buf.is_unique());
+        assert!(buf.ref_count().is_unique());
+        assert!(buf.ref_count().can_be_reclaimed());
+
+        let clone = buf.clone();
+        assert!(!buf.is_unique());
+        assert!(!clone.ref_count().is_unique());
+
+        drop(clone);
+        assert!(buf.is_unique());
+        drop(reclaim);
+        assert!(buf.ref_count().is_unique());
+    }
+
+    #[test]
+    fn try_unsplit_joins_split_halves() {
+        let mut buf = ArcBufMut::new(16);
+        buf.put(b"Hello World" as &[u8]).unwrap();
+        let tail = buf.split_off(5).unwrap();
+        assert_eq!(buf.ref_count().ref_count(), Some(2));
+
+        buf.try_unsplit(tail).unwrap();
+        assert_eq!(buf, b"Hello World");
+        assert_eq!(buf.capacity(), 16);
+        assert_eq!(buf.ref_count().ref_count(), Some(1));
+
+        let other: ArcBufMut = b"!".iter().copied().collect();
+        assert_eq!(buf.try_unsplit(other).unwrap_err(), b"!");
+    }
+
+    #[test]
+    fn truncate_keeps_prefix() {
+        let mut buf = ArcBufMut::new(16);
+        buf.put(b"0123456789" as &[u8]).unwrap();
+        buf.truncate(4);
+        assert_eq!(buf.as_ref(), b"0123");
+
+        buf.truncate(10);
+        assert_eq!(buf.len(), 4);
+
+        buf.put(b"abc" as &[u8]).unwrap();
+        assert_eq!(buf.as_ref(), b"0123abc");
+    }
+
+    #[test]
+    fn it_prepends_into_headroom() {
+        let mut buf = ArcBufMut::with_headroom(16, 4);
+        assert_eq!(buf.headroom(), 4);
+        buf.put(b"Hello" as &[u8]).unwrap();
+        let body = buf.as_ref().as_ptr();
+
+        buf.prepend(b"\x00\x05").unwrap();
+        buf.prepend(b"\xab\xcd").unwrap();
+        assert_eq!(buf, b"\xab\xcd\x00\x05Hello");
+        assert_eq!(buf.headroom(), 0);
+        assert_eq!(unsafe { buf.as_ref().as_ptr().add(4) }, body);
+
+        assert_eq!(
+            buf.prepend(b"!").unwrap_err(),
+            Full {
+                required: 1,
+                capacity: 0,
+            }
+        );
+        assert_eq!(buf.freeze(), b"\xab\xcd\x00\x05Hello");
+    }
+
+    #[test]
+    fn growing_keeps_headroom() {
+        let mut buf = ArcBufMut::with_headroom(4, 2);
+        buf.extend(b"Hello World".iter());
+        assert_eq!(buf, b"Hello World");
+        assert_eq!(buf.headroom(), 2);
+
+        buf.prepend(b"> ").unwrap();
+        assert_eq!(buf, b"> Hello World");
+    }
+
+    #[test]
+    fn split_keeps_headroom_with_left_half() {
+        let mut buf = ArcBufMut::with_headroom(16, 4);
+        buf.put(b"Hello World" as &[u8]).unwrap();
+
+        let mut left = buf.split_at(5).unwrap();
+        assert_eq!(left.headroom(), 4);
+        assert_eq!(buf.headroom(), 0);
+        assert!(buf.prepend(b"!").is_err());
+
+        left.prepend(b">").unwrap();
+        assert_eq!(left, b">Hello");
+        assert_eq!(buf, b" World");
+    }
+
+    #[test]
+    fn it_adopts_boxed_slice() {
+        let boxed: Box<[u8]> = b"Hello World".to_vec().into_boxed_slice();
+        let ptr = boxed.as_ptr();
+
+        let mut buf = ArcBufMut::from(boxed);
+        assert_eq!(buf.capacity(), 11);
+        assert_eq!(buf.initialized().as_ptr(), ptr);
+        assert_eq!(buf, b"Hello World");
+
+        buf.initialized_mut()[..5].copy_from_slice(b"HELLO");
+        let bytes = Bytes::from(buf.freeze());
+        assert_eq!(bytes, b"HELLO World");
+        assert_eq!(bytes.contiguous().unwrap().as_ptr(), ptr);
+
+        let buf = ArcBufMut::from(Box::<[u8]>::default());
+        assert!(buf.is_empty());
+        assert!(buf.ref_count().is_static());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_exposes_spare_capacity_as_io_slice() {
+        use std::io::{
+            IoSliceMut,
+            Read,
+        };
+
+        let mut buf = ArcBufMut::new(16);
+        copy(&mut buf, b"Hello").unwrap();
+
+        let n_read = {
+            let mut slices = [IoSliceMut::new(&mut []), IoSliceMut::new(&mut [])];
+            assert_eq!(buf.io_slices_mut(&mut slices), 1);
+            assert_eq!(slices[0].len(), 11);
+            (b" World" as &[u8]).read_vectored(&mut slices).unwrap()
+        };
+        assert_eq!(n_read, 6);
+        buf.set_filled_to(5 + n_read);
+        assert_eq!(buf, b"Hello World");
+
+        let mut buf = ArcBufMut::new(5);
+        copy(&mut buf, b"Hello").unwrap();
+        let mut slices = [IoSliceMut::new(&mut [])];
+        assert_eq!(buf.io_slices_mut(&mut slices), 0);
+    }
+
+    #[test]
+    fn it_indexes_bytes() {
+        let buf: ArcBufMut = b"Hello".iter().copied().collect();
+        let buf = buf.freeze();
+        assert_eq!(buf[0], b'H');
+        assert_eq!(buf[4], b'o');
+        assert_eq!(buf.get(4), Some(b'o'));
+        assert_eq!(buf.get(5), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_on_index_out_of_bounds() {
+        let buf: ArcBufMut = b"Hello".iter().copied().collect();
+        let _ = buf.freeze()[5];
+    }
+
+    #[test]
+    fn it_formats_as_hex() {
+        let buf: ArcBufMut = b"\x0a\xff".iter().copied().collect();
+        let buf = buf.freeze();
+        assert_eq!(format!("{buf:x}"), "0aff");
+        assert_eq!(format!("{buf:#X}"), "0X0AFF");
+    }
+
+    #[test]
+    fn it_collects_from_iterator() {
+        let buf = (0..10u8).collect::<ArcBufMut>();
+        assert_eq!(buf, b"\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09");
+
+        let buf = std::iter::empty::<u8>().collect::<ArcBufMut>();
+        assert!(buf.is_empty());
+        assert!(buf.ref_count().is_static());
+    }
+
+    #[test]
+    fn it_allocates_aligned_buffers() {
+        for align in [1, 2, 16, 64, 4096] {
+            let mut buf = ArcBufMut::with_alignment(100, align);
+            buf.fully_initialize();
+            assert_eq!(buf.initialized_mut().as_ptr() as usize % align, 0);
+            assert_eq!(buf.alignment(), align);
+        }
+
+        let buf = ArcBufMut::with_alignment(0, 64);
+        assert_eq!(buf.alignment(), 64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_rejects_invalid_alignment() {
+        let _ = ArcBufMut::with_alignment(10, 3);
+    }
+
+    #[test]
+    fn weak_buf_upgrades_while_alive() {
+        let buf = b"Hello World"
+            .iter()
+            .copied()
+            .collect::<ArcBufMut>()
+            .freeze();
+        let view = Buf::view(&buf, 6..).unwrap();
+        let weak = view.downgrade();
+        drop(view);
+
+        let upgraded = weak.upgrade().unwrap();
+        assert_eq!(upgraded, b"World");
+        assert_eq!(buf.ref_count().ref_count(), Some(2));
+
+        drop(upgraded);
+        drop(buf);
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn weak_buf_doesnt_upgrade_to_reclaimed_buf() {
+        let mut buf = ArcBufMut::new(16);
+        copy(&mut buf, b"Hello").unwrap();
+        let (buf, reclaim) = buf.freeze_reclaimable().unwrap();
+        let weak = buf.downgrade();
+        drop(buf);
+
+        let mut reclaimed = reclaim.try_reclaim().unwrap();
+        assert!(weak.upgrade().is_none());
+        copy(&mut reclaimed, b"World").unwrap();
+        let reclaimed = reclaimed.freeze();
+        assert!(weak.upgrade().is_none());
+        assert_eq!(reclaimed.ref_count().ref_count(), Some(1));
+        assert!(reclaimed.is_unique());
+    }
+
+    #[test]
+    fn weak_buf_prevents_into_mut() {
+        let buf = b"Hello".iter().copied().collect::<ArcBufMut>().freeze();
+        let weak = buf.downgrade();
+        assert!(!buf.is_unique());
+        let buf = buf.try_into_mut().unwrap_err();
+
+        drop(weak);
+        assert!(buf.is_unique());
+        let buf = buf.try_into_mut().unwrap();
+        assert_eq!(buf, b"Hello");
+    }
+
+    #[test]
+    fn it_pads_to_boundary() {
+        let mut buf = ArcBufMut::new(32);
+        {
+            let mut writer = BufMut::writer(&mut buf);
+            BufWriter::extend(&mut writer, b"Hello").unwrap();
+            BufWriter::fill(&mut writer, 0xff, 16 - 5).unwrap();
+            assert!(BufWriter::fill(&mut writer, 0xff, 17).is_err());
+        }
+        assert_eq!(buf, b"Hello\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff");
+    }
+
+    #[test]
+    fn it_patches_length_prefix() {
+        let mut buf = ArcBufMut::new(16);
+        let mut writer = BufMut::writer(&mut buf);
+        writer.write_with(&0u32, NetworkEndian).unwrap();
+        writer.write_buf(b"Hello").unwrap();
+        let length = writer.position() - 4;
+
+        let mut writer = buf.writer_at(0).unwrap();
+        writer.write_with(&(length as u32), NetworkEndian).unwrap();
+        assert_eq!(buf, b"\x00\x00\x00\x05Hello");
+
+        let mut writer = buf.writer_at(7).unwrap();
+        writer.write_buf(b"p!").unwrap();
+        assert_eq!(buf, b"\x00\x00\x00\x05Help!");
+
+        assert!(buf.writer_at(10).is_err());
+        let mut writer = buf.writer_at(9).unwrap();
+        writer.write_buf(b"!!").unwrap();
+        assert_eq!(buf, b"\x00\x00\x00\x05Help!!!");
+    }
+
+    #[test]
+    fn it_grows_when_extended() {
+        let mut buf = ArcBufMut::new(4);
+        copy(&mut buf, b"abcd").unwrap();
+        buf.extend(b"efgh".iter().filter(|_| true));
+        assert_eq!(buf, b"abcdefgh");
+        assert!(buf.capacity() >= 8);
+    }
+
     #[test]
     fn it_reclaims_empty_buffers_correctly() {
         // don't ask me why we have specifically this test lol
@@ -1434,6 +2615,140 @@ mod tests {
         assert!(reclaimed.ref_count().is_static());
     }
 
+    #[test]
+    fn reclaim_reports_outstanding_references() {
+        let (mut buf, reclaim) = ArcBufMut::new_reclaimable(16);
+        copy(&mut buf, b"Hello World").unwrap();
+        assert_eq!(reclaim.outstanding(), 1);
+
+        let buf = buf.freeze();
+        let first = Buf::view(&buf, ..5).unwrap();
+        let second = Buf::view(&buf, 6..).unwrap();
+        let third = first.clone();
+        assert_eq!(reclaim.outstanding(), 4);
+        assert_eq!(reclaim.try_reclaim_or_count().unwrap_err(), 4);
+
+        drop(buf);
+        drop(third);
+        assert_eq!(reclaim.outstanding(), 2);
+        assert_eq!(reclaim.try_reclaim_or_count().unwrap_err(), 2);
+
+        drop(first);
+        drop(second);
+        assert_eq!(reclaim.outstanding(), 0);
+        let reclaimed = reclaim.try_reclaim_or_count().unwrap();
+        assert_eq!(reclaim.outstanding(), 1);
+        assert_eq!(reclaimed.capacity(), 16);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn it_zeroizes() {
+        let mut buf = ArcBufMut::new(16);
+        copy(&mut buf, b"Hello World").unwrap();
+        buf.zeroize();
+        assert!(buf.is_empty());
+        assert_eq!(buf.initialized(), [0; 11]);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn it_zeroizes_on_reclaim() {
+        let (mut buf, reclaim) = ArcBufMut::new_reclaimable(16);
+        copy(&mut buf, b"Hello World").unwrap();
+        drop(buf);
+        let reclaimed = reclaim.try_reclaim_zeroized().unwrap();
+        assert!(reclaimed.is_empty());
+        assert_eq!(reclaimed.initialized(), [0; 11]);
+    }
+
+    #[test]
+    fn it_reclaims_frozen_buffers() {
+        let mut buf = ArcBufMut::new(16);
+        copy(&mut buf, b"Hello World").unwrap();
+        let (frozen, reclaim) = buf.freeze_reclaimable().unwrap();
+        let clone = frozen.clone();
+        assert_eq!(clone, b"Hello World");
+        assert!(reclaim.try_reclaim().is_none());
+
+        drop(frozen);
+        assert!(reclaim.try_reclaim().is_none());
+        drop(clone);
+
+        let mut reclaimed = reclaim.try_reclaim().unwrap();
+        assert!(reclaimed.is_empty());
+        assert_eq!(reclaimed.capacity(), 16);
+        copy(&mut reclaimed, b"Hello").unwrap();
+        assert_eq!(reclaimed, b"Hello");
+    }
+
+    #[test]
+    fn freeze_reclaimable_fails_if_already_reclaimable() {
+        let (buf, _reclaim) = ArcBufMut::new_reclaimable(16);
+        let buf = buf.freeze_reclaimable().unwrap_err();
+        assert_eq!(buf.capacity(), 16);
+    }
+
+    #[test]
+    fn it_formats_into_buffer() {
+        use std::fmt::Write;
+
+        let mut buf = ArcBufMut::new(8);
+        write!(buf, "x={}", 42).unwrap();
+        assert_eq!(buf, b"x=42");
+        buf.write_str(", y=1337").unwrap_err();
+        assert_eq!(buf, b"x=42");
+    }
+
+    #[test]
+    fn it_writes_vectored_all_or_nothing() {
+        let mut buf = ArcBufMut::new(16);
+        let mut writer = buf.writer();
+        writer
+            .write_all_vectored(&[b"Hello", b" ", b"World"])
+            .unwrap();
+        writer.write_all_vectored(&[b"!!", b"Hello"]).unwrap_err();
+        assert_eq!(buf, b"Hello World");
+    }
+
+    #[test]
+    fn split_off_keeps_head() {
+        let mut buf = ArcBufMut::new(16);
+        copy(&mut buf, b"Hello World").unwrap();
+
+        let mut tail = buf.split_off(6).unwrap();
+        assert_eq!(buf, b"Hello ");
+        assert_eq!(tail, b"World");
+
+        tail.put(b"!!!" as &[u8]).unwrap();
+        assert_eq!(tail, b"World!!!");
+        assert_eq!(buf, b"Hello ");
+
+        let rest = tail.split_off(8).unwrap();
+        assert_eq!(tail, b"World!!!");
+        assert!(rest.is_empty());
+        assert!(buf.split_off(7).is_err());
+    }
+
+    #[test]
+    fn it_converts_unique_buffer_into_mut() {
+        let mut buf = ArcBufMut::new(16);
+        copy(&mut buf, b"Hello World").unwrap();
+        let frozen = buf.freeze();
+
+        let clone = frozen.clone();
+        let frozen = frozen.try_into_mut().unwrap_err();
+        let weak = clone.downgrade();
+        drop(clone);
+        let frozen = frozen.try_into_mut().unwrap_err();
+        drop(weak);
+
+        let mut buf = frozen.try_into_mut().unwrap();
+        assert_eq!(buf, b"Hello World");
+        buf.view_mut(..5).unwrap().copy_from_slice(b"HELLO");
+        assert_eq!(buf, b"HELLO World");
+    }
+
     #[test]
     fn empty_bufs_dont_ref_count() {
         let buf = ArcBufMut::new(10);
@@ -1472,6 +2787,19 @@ mod tests {
         assert_eq!(buf, b" World. This is");
     }
 
+    #[test]
+    fn split_bufs_split_again_correctly() {
+        let mut buf = ArcBufMut::new(20);
+        copy(&mut buf, b"Hello World. This is").unwrap();
+
+        let first = buf.split_at(6).unwrap();
+        let second = buf.split_at(6).unwrap();
+
+        assert_eq!(first, b"Hello ");
+        assert_eq!(second, b"World.");
+        assert_eq!(buf, b" This is");
+    }
+
     #[test]
     fn split_off_buf_doesnt_spill_into_other_half() {
         let mut buf = ArcBufMut::new(20);
@@ -1,4 +1,4 @@
-use std::{
+use core::{
     fmt::Debug,
     mem::MaybeUninit,
 };
@@ -54,6 +54,14 @@ impl<const N: usize> ArrayBuf<N> {
         self.inner.clear();
     }
 
+    /// Shortens the buffer to the first `len` bytes.
+    ///
+    /// If `len` is greater or equal to the current length, this has no effect.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
     #[inline]
     pub fn inner_ref(&self) -> &[MaybeUninit<u8>; N] {
         self.inner.inner_ref()
@@ -107,7 +115,7 @@ impl<const N: usize> Clone for ArrayBuf<N> {
 }
 
 impl<const N: usize> Debug for ArrayBuf<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Debug::fmt(&self.inner, f)
     }
 }
@@ -281,6 +289,16 @@ mod tests {
 
     buf_mut_tests!(ArrayBuf::<20>::new());
 
+    #[test]
+    fn truncate_keeps_prefix() {
+        let mut buf = ArrayBuf::<16>::new();
+        copy(&mut buf, b"0123456789").unwrap();
+        buf.truncate(4);
+        assert_eq!(buf, b"0123");
+        buf.truncate(10);
+        assert_eq!(buf, b"0123");
+    }
+
     #[test]
     fn cant_write_more_than_buf_size() {
         let mut bytes_mut = ArrayBuf::<4>::new();
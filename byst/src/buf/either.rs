@@ -0,0 +1,337 @@
+#[cfg(feature = "std")]
+use std::io::{
+    IoSlice,
+    IoSliceMut,
+};
+
+use super::{
+    Buf,
+    BufMut,
+    Full,
+    Length,
+    SizeLimit,
+};
+use crate::{
+    impl_me,
+    io::{
+        BufReader,
+        BufWriter,
+        End,
+        Seek,
+    },
+    util::buf_eq,
+    Range,
+    RangeOutOfBounds,
+};
+
+/// Either buffer (or reader, or writer) `A` or `B`.
+///
+/// This can be used to return one of two buffer types without boxing them.
+#[derive(Clone, Copy, Debug)]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+macro_rules! dispatch {
+    ($self:expr, $inner:ident => $expr:expr) => {
+        match $self {
+            Either::Left($inner) => $expr,
+            Either::Right($inner) => $expr,
+        }
+    };
+    ($self:expr, $inner:ident => wrap $expr:expr) => {
+        match $self {
+            Either::Left($inner) => Either::Left($expr),
+            Either::Right($inner) => Either::Right($expr),
+        }
+    };
+}
+
+impl<A: Length, B: Length> Length for Either<A, B> {
+    #[inline]
+    fn len(&self) -> usize {
+        dispatch!(self, inner => inner.len())
+    }
+}
+
+impl<A: Buf, B: Buf> Buf for Either<A, B> {
+    type View<'a> = Either<A::View<'a>, B::View<'a>>
+    where
+        Self: 'a;
+
+    type Reader<'a> = Either<A::Reader<'a>, B::Reader<'a>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn view(&self, range: impl Into<Range>) -> Result<Self::View<'_>, RangeOutOfBounds> {
+        Ok(dispatch!(self, inner => wrap Buf::view(inner, range)?))
+    }
+
+    #[inline]
+    fn reader(&self) -> Self::Reader<'_> {
+        dispatch!(self, inner => wrap inner.reader())
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        dispatch!(self, inner => inner.is_contiguous())
+    }
+}
+
+impl<A: BufMut, B: BufMut> BufMut for Either<A, B> {
+    type ViewMut<'a> = Either<A::ViewMut<'a>, B::ViewMut<'a>>
+    where
+        Self: 'a;
+
+    type Writer<'a> = Either<A::Writer<'a>, B::Writer<'a>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn view_mut(&mut self, range: impl Into<Range>) -> Result<Self::ViewMut<'_>, RangeOutOfBounds> {
+        Ok(dispatch!(self, inner => wrap BufMut::view_mut(inner, range)?))
+    }
+
+    #[inline]
+    fn writer(&mut self) -> Self::Writer<'_> {
+        dispatch!(self, inner => wrap inner.writer())
+    }
+
+    #[inline]
+    fn reserve(&mut self, size: usize) -> Result<(), Full> {
+        dispatch!(self, inner => inner.reserve(size))
+    }
+
+    #[inline]
+    fn size_limit(&self) -> SizeLimit {
+        dispatch!(self, inner => inner.size_limit())
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn io_slices_mut<'a>(&'a mut self, dst: &mut [IoSliceMut<'a>]) -> usize {
+        dispatch!(self, inner => inner.io_slices_mut(dst))
+    }
+}
+
+impl<A: BufReader, B: BufReader> BufReader for Either<A, B> {
+    type View = Either<A::View, B::View>;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        dispatch!(self, inner => inner.peek_chunk())
+    }
+
+    #[inline]
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        Ok(dispatch!(self, inner => wrap BufReader::view(inner, length)?))
+    }
+
+    #[inline]
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        Ok(dispatch!(self, inner => wrap inner.peek_view(length)?))
+    }
+
+    #[inline]
+    fn rest(&mut self) -> Self::View {
+        dispatch!(self, inner => wrap inner.rest())
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        dispatch!(self, inner => wrap inner.peek_rest())
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        dispatch!(self, inner => BufReader::advance(inner, by))
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        dispatch!(self, inner => BufReader::remaining(inner))
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn peek_io_slices<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        dispatch!(self, inner => inner.peek_io_slices(dst))
+    }
+}
+
+impl<A: Seek, B: Seek> Seek for Either<A, B> {
+    type Position = Either<A::Position, B::Position>;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        dispatch!(self, inner => wrap inner.tell())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `position` was returned for the other variant.
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        match (self, position) {
+            (Either::Left(inner), Either::Left(position)) => Either::Left(inner.seek(position)),
+            (Either::Right(inner), Either::Right(position)) => Either::Right(inner.seek(position)),
+            _ => panic!("Can't seek to a position of the other variant"),
+        }
+    }
+}
+
+impl<A: BufWriter, B: BufWriter> BufWriter for Either<A, B> {
+    type ViewMut<'a> = Either<A::ViewMut<'a>, B::ViewMut<'a>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn peek_chunk_mut(&mut self) -> Option<&mut [u8]> {
+        dispatch!(self, inner => inner.peek_chunk_mut())
+    }
+
+    #[inline]
+    fn view_mut(&mut self, length: usize) -> Result<Self::ViewMut<'_>, crate::io::Full> {
+        Ok(dispatch!(self, inner => wrap BufWriter::view_mut(inner, length)?))
+    }
+
+    #[inline]
+    fn peek_view_mut(&mut self, length: usize) -> Result<Self::ViewMut<'_>, crate::io::Full> {
+        Ok(dispatch!(self, inner => wrap inner.peek_view_mut(length)?))
+    }
+
+    #[inline]
+    fn rest_mut(&mut self) -> Self::ViewMut<'_> {
+        dispatch!(self, inner => wrap inner.rest_mut())
+    }
+
+    #[inline]
+    fn peek_rest_mut(&mut self) -> Self::ViewMut<'_> {
+        dispatch!(self, inner => wrap inner.peek_rest_mut())
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), crate::io::Full> {
+        dispatch!(self, inner => BufWriter::advance(inner, by))
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        dispatch!(self, inner => BufWriter::remaining(inner))
+    }
+
+    #[inline]
+    fn extend(&mut self, with: &[u8]) -> Result<(), crate::io::Full> {
+        dispatch!(self, inner => BufWriter::extend(inner, with))
+    }
+
+    #[inline]
+    fn fill(&mut self, byte: u8, count: usize) -> Result<(), crate::io::Full> {
+        dispatch!(self, inner => BufWriter::fill(inner, byte, count))
+    }
+
+    #[inline]
+    fn write_all_vectored(&mut self, bufs: &[&[u8]]) -> Result<(), crate::io::Full> {
+        dispatch!(self, inner => inner.write_all_vectored(bufs))
+    }
+}
+
+impl<A: Buf, B: Buf, T: Buf> PartialEq<T> for Either<A, B> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        buf_eq(self, other)
+    }
+}
+
+// note: `Either` forwards to a reader and a writer, so only the `Reader` impl
+// is generated with `impl_me!` below. Generating this one too would implement
+// `Remaining` for `Either` a second time.
+impl<A: BufWriter, B: BufWriter> crate::io::Writer for Either<A, B> {
+    type Error = crate::io::Full;
+
+    fn write_buf<T: Buf>(&mut self, buf: T) -> Result<(), crate::io::Full> {
+        let n_copied = crate::copy_io(&mut *self, buf.reader(), None);
+        if n_copied < buf.len() {
+            Err(crate::io::Full {
+                written: n_copied,
+                requested: buf.len(),
+                remaining: buf.len() - n_copied,
+            })
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn skip(&mut self, amount: usize) -> Result<(), crate::io::Full> {
+        BufWriter::advance(self, amount)
+    }
+}
+
+impl_me! {
+    impl[A: BufReader, B: BufReader] Reader for Either<A, B> as BufReader;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Either;
+    use crate::{
+        buf::{
+            arc_buf::ArcBufMut,
+            Length,
+        },
+        io::{
+            BufReader,
+            BufWriter,
+        },
+        Buf,
+        BufMut,
+        Bytes,
+    };
+
+    fn either(left: bool) -> Either<&'static [u8], Bytes> {
+        if left {
+            Either::Left(b"Hello World")
+        }
+        else {
+            Either::Right(Bytes::from(b"Hello Bytes" as &'static [u8]))
+        }
+    }
+
+    #[test]
+    fn it_forwards_view_and_len() {
+        let left = either(true);
+        assert_eq!(left.len(), 11);
+        assert!(matches!(
+            Buf::view(&left, 6..).unwrap(),
+            Either::Left(b"World")
+        ));
+
+        let right = either(false);
+        assert_eq!(right.len(), 11);
+        let view = Buf::view(&right, 6..).unwrap();
+        assert!(matches!(view, Either::Right(_)));
+        assert_eq!(view, b"Bytes");
+        assert_eq!(right, b"Hello Bytes");
+    }
+
+    #[test]
+    fn it_forwards_reader() {
+        let mut reader = either(false).reader();
+        assert_eq!(BufReader::view(&mut reader, 5).unwrap(), b"Hello");
+        assert_eq!(reader.remaining(), 6);
+    }
+
+    #[test]
+    fn it_forwards_writer() {
+        let mut buf: Either<ArcBufMut, Vec<u8>> = Either::Left(ArcBufMut::new(8));
+        BufWriter::extend(&mut buf.writer(), b"Hello").unwrap();
+        assert_eq!(buf, b"Hello");
+
+        let mut buf: Either<ArcBufMut, Vec<u8>> = Either::Right(vec![]);
+        BufWriter::extend(&mut buf.writer(), b"Hello World").unwrap();
+        assert_eq!(buf, b"Hello World");
+    }
+}
@@ -1,9 +1,11 @@
-use std::ops::{
+use alloc::boxed::Box;
+use core::ops::{
     Deref,
     DerefMut,
 };
 
 use super::{
+    arc_buf::ArcBufMut,
     Buf,
     BufReader,
     BufWriter,
@@ -60,6 +62,11 @@ impl Buf for Empty {
     fn reader(&self) -> Self {
         Self
     }
+
+    #[inline]
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
 }
 
 impl BufMut for Empty {
@@ -285,9 +292,15 @@ impl<'b> BytesImpl<'b> for Empty {
     fn advance(&mut self, by: usize) -> Result<(), End> {
         BufReader::advance(self, by)
     }
+
+    fn try_into_mut(
+        self: Box<Self>,
+    ) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, Box<dyn BytesImpl<'b> + 'b>> {
+        Err(self)
+    }
 }
 
-impl BytesMutImpl for Empty {
+impl<'b> BytesMutImpl<'b> for Empty {
     fn view(&self, range: Range) -> Result<Box<dyn BytesImpl<'_> + '_>, RangeOutOfBounds> {
         check_range(range)?;
         Ok(Box::new(Self))
@@ -296,7 +309,7 @@ impl BytesMutImpl for Empty {
     fn view_mut(
         &mut self,
         range: Range,
-    ) -> Result<Box<dyn BytesMutImpl + 'static>, RangeOutOfBounds> {
+    ) -> Result<Box<dyn BytesMutImpl<'_> + '_>, RangeOutOfBounds> {
         check_range(range)?;
         Ok(Box::new(Self))
     }
@@ -317,7 +330,7 @@ impl BytesMutImpl for Empty {
         BufMut::size_limit(self)
     }
 
-    fn split_at(&mut self, at: usize) -> Result<Box<dyn BytesMutImpl + '_>, IndexOutOfBounds> {
+    fn split_at(&mut self, at: usize) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, IndexOutOfBounds> {
         if at == 0 {
             Ok(Box::new(Self))
         }
@@ -328,6 +341,15 @@ impl BytesMutImpl for Empty {
             })
         }
     }
+
+    fn freeze(self: Box<Self>) -> Box<dyn BytesImpl<'b> + 'b> {
+        Box::new(Self)
+    }
+
+    fn into_arc_buf_mut(self: Box<Self>) -> Result<ArcBufMut, Box<dyn BytesMutImpl<'b> + 'b>> {
+        // a zero-sized buffer doesn't allocate.
+        Ok(ArcBufMut::new(0))
+    }
 }
 
 impl WriterImpl for Empty {
@@ -0,0 +1,270 @@
+use std::{
+    fmt::Debug,
+    fs::File,
+    path::Path,
+    sync::Arc,
+};
+
+use memmap2::Mmap;
+
+use super::{
+    Buf,
+    BufReader,
+    Length,
+};
+use crate::{
+    bytes::r#impl::{
+        BytesImpl,
+        BytesMutImpl,
+    },
+    impl_me,
+    io::{
+        End,
+        Seek,
+    },
+    util::{
+        buf_eq,
+        debug_as_hexdump,
+    },
+    Bytes,
+    Range,
+    RangeOutOfBounds,
+};
+
+/// A read-only memory-mapped file.
+///
+/// The memory map is reference-counted, so views into it are zero-copy and
+/// keep it alive.
+#[derive(Clone)]
+pub struct MmapBuf {
+    mmap: Arc<Mmap>,
+    start: usize,
+    end: usize,
+}
+
+impl MmapBuf {
+    /// Opens the file at `path` and maps it into memory.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified while it's mapped, e.g. by another
+    /// process. See [`Mmap::map`] for details.
+    pub unsafe fn open(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        let file = File::open(path)?;
+        let mmap = unsafe { Mmap::map(&file)? };
+        Ok(mmap.into())
+    }
+
+    #[inline]
+    fn bytes(&self) -> &[u8] {
+        &self.mmap[self.start..self.end]
+    }
+}
+
+impl From<Mmap> for MmapBuf {
+    #[inline]
+    fn from(mmap: Mmap) -> Self {
+        let end = mmap.len();
+        Self {
+            mmap: Arc::new(mmap),
+            start: 0,
+            end,
+        }
+    }
+}
+
+impl From<MmapBuf> for Bytes {
+    #[inline]
+    fn from(value: MmapBuf) -> Self {
+        Bytes::from_impl(Box::new(value))
+    }
+}
+
+impl Buf for MmapBuf {
+    type View<'a> = Self
+    where
+        Self: 'a;
+
+    type Reader<'a> = Self
+    where
+        Self: 'a;
+
+    fn view(&self, range: impl Into<Range>) -> Result<Self::View<'_>, RangeOutOfBounds> {
+        let (start, end) = range.into().indices_checked_in(self.start, self.end)?;
+        Ok(Self {
+            mmap: Arc::clone(&self.mmap),
+            start,
+            end,
+        })
+    }
+
+    #[inline]
+    fn reader(&self) -> Self::Reader<'_> {
+        Clone::clone(self)
+    }
+}
+
+impl Length for MmapBuf {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl BufReader for MmapBuf {
+    type View = Self;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        if self.is_empty() {
+            None
+        }
+        else {
+            Some(self.bytes())
+        }
+    }
+
+    #[inline]
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        let view = self.peek_view(length)?;
+        self.start += length;
+        Ok(view)
+    }
+
+    #[inline]
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        Buf::view(self, ..length).map_err(|RangeOutOfBounds { .. }| {
+            End {
+                requested: length,
+                read: 0,
+                remaining: self.len(),
+            }
+        })
+    }
+
+    #[inline]
+    fn rest(&mut self) -> Self::View {
+        let rest = Clone::clone(self);
+        self.start = self.end;
+        rest
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        Clone::clone(self)
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        if by <= self.len() {
+            self.start += by;
+            Ok(())
+        }
+        else {
+            Err(End {
+                requested: by,
+                read: 0,
+                remaining: self.len(),
+            })
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Seek for MmapBuf {
+    type Position = MmapBuf;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        Clone::clone(self)
+    }
+
+    #[inline]
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        std::mem::replace(self, Clone::clone(position))
+    }
+}
+
+impl<'b> BytesImpl<'b> for MmapBuf {
+    fn view(&self, range: Range) -> Result<Box<dyn BytesImpl<'b> + 'b>, RangeOutOfBounds> {
+        Ok(Box::new(Buf::view(self, range)?))
+    }
+
+    fn clone(&self) -> Box<dyn BytesImpl<'b> + 'b> {
+        Box::new(Clone::clone(self))
+    }
+
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        BufReader::peek_chunk(self)
+    }
+
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        BufReader::advance(self, by)
+    }
+
+    fn try_into_mut(
+        self: Box<Self>,
+    ) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, Box<dyn BytesImpl<'b> + 'b>> {
+        Err(self)
+    }
+}
+
+impl AsRef<[u8]> for MmapBuf {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.bytes()
+    }
+}
+
+impl Debug for MmapBuf {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        debug_as_hexdump(f, self.bytes())
+    }
+}
+
+impl<T: Buf> PartialEq<T> for MmapBuf {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        buf_eq(self, other)
+    }
+}
+
+impl_me! {
+    impl[] Reader for MmapBuf as BufReader;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MmapBuf;
+    use crate::{
+        endianness::BigEndian,
+        io::{
+            BufReader,
+            ReaderExt,
+        },
+        Buf,
+        Bytes,
+    };
+
+    #[test]
+    fn it_reads_from_mapped_file() {
+        let path = std::env::temp_dir().join(format!("byst-mmap-test-{}", std::process::id()));
+        std::fs::write(&path, b"Hello \x12\x34\x56\x78 World").unwrap();
+
+        let buf = unsafe { MmapBuf::open(&path) }.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut reader = Buf::view(&buf, 6..).unwrap();
+        assert_eq!(reader.read_with::<u32, _>(BigEndian).unwrap(), 0x12345678);
+        assert_eq!(reader.peek_chunk(), Some(b" World" as &[u8]));
+
+        let bytes = Bytes::from(buf);
+        drop(reader);
+        assert_eq!(Buf::view(&bytes, ..5).unwrap(), b"Hello");
+    }
+}
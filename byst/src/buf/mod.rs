@@ -1,26 +1,46 @@
 pub mod arc_buf;
 pub mod array_buf;
 pub mod chunks;
+pub mod either;
 mod empty;
+#[cfg(feature = "mmap")]
+pub mod mmap;
 mod partially_initialized;
+#[cfg(feature = "std")]
+pub mod pool;
+pub mod ring_buf;
 pub mod rope;
+pub mod segments;
+mod sink;
 mod slab;
 
-use std::{
-    borrow::Cow,
+use alloc::{
+    borrow::{
+        Cow,
+        ToOwned,
+    },
+    boxed::Box,
+    rc::Rc,
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
     fmt::Debug,
     ops::{
+        ControlFlow,
         Deref,
         DerefMut,
     },
-    rc::Rc,
-    sync::Arc,
 };
+#[cfg(feature = "std")]
+use std::io::IoSliceMut;
 
 use chunks::BufIter;
 
 pub use self::{
     empty::Empty,
+    sink::Sink,
     slab::Slab,
 };
 use super::range::{
@@ -28,11 +48,24 @@ use super::range::{
     RangeOutOfBounds,
 };
 use crate::{
+    copy,
+    copy_io,
+    hexdump::Hexdump,
     impl_me,
     io::{
         BufReader,
         BufWriter,
+        Seek,
+    },
+    util::{
+        buf_cmp,
+        buf_eq,
+        find_in_reader,
+        for_each_chunk,
+        try_for_each_chunk,
     },
+    Bytes,
+    BytesMut,
 };
 
 pub trait Length {
@@ -44,6 +77,17 @@ pub trait Length {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns whether this buffer has the same length as `other`.
+    ///
+    /// This only compares the lengths and never touches the contents.
+    #[inline]
+    fn same_len_as(&self, other: &(impl Length + ?Sized)) -> bool
+    where
+        Self: Sized,
+    {
+        self.len() == other.len()
+    }
 }
 
 /// Read access to a buffer of bytes.
@@ -63,15 +107,179 @@ pub trait Buf: Length {
     /// Returns a [`BufReader`] for this buffer.
     fn reader(&self) -> Self::Reader<'_>;
 
+    /// Returns a [`BufReader`] for this buffer, that starts at `position`.
+    ///
+    /// The position is one previously returned by [`Seek::tell`] on a reader
+    /// of this buffer. This can be used to resume reading where a previous
+    /// reader left off.
+    #[inline]
+    fn reader_from<'a>(
+        &'a self,
+        position: &<Self::Reader<'a> as Seek>::Position,
+    ) -> Self::Reader<'a> {
+        let mut reader = self.reader();
+        reader.seek(position);
+        reader
+    }
+
+    /// Returns a view of a portion of the buffer, clamping the range to the
+    /// buffer's bounds.
+    ///
+    /// Unlike [`Buf::view`] this never fails. If the range lies completely
+    /// outside of the buffer, an empty view is returned.
+    #[inline]
+    fn view_clamped(&self, range: impl Into<Range>) -> Self::View<'_> {
+        let (start, end) = range.into().indices_clamped_in(0, self.len());
+        self.view((start, end))
+            .expect("Clamped range is within bounds")
+    }
+
     /// Returns whether this buffer contains bytes for the given range.
     ///
+    /// This is `true` if [`view`](Self::view) would succeed for `range`, but
+    /// doesn't construct a view. An unbounded end is treated as `self.len()`,
+    /// so empty ranges at the end of the buffer are contained.
+    ///
     /// # Default implementation
     ///
-    /// The default implementation will check if the range is contained by
-    /// `..self.len()`.
+    /// The default implementation will check if the range lies within
+    /// `0..self.len()`.
     #[inline]
     fn contains(&self, range: impl Into<Range>) -> bool {
-        range.into().contained_by(..self.len())
+        range.into().indices_checked_in(0, self.len()).is_ok()
+    }
+
+    /// Returns the byte at `index`, or `None` if it's out of bounds.
+    #[inline]
+    fn get(&self, index: usize) -> Option<u8> {
+        let mut reader = self.reader();
+        reader.advance(index).ok()?;
+        reader.peek_u8().ok()
+    }
+
+    /// Copies the buffer into `dest`, which must have the same length.
+    ///
+    /// This walks the chunks of the buffer, so it doesn't need to be
+    /// contiguous. If the lengths differ, this fails without copying anything.
+    /// Use [`Buf::view`] first to copy only a portion of the buffer.
+    fn copy_to_slice(&self, dest: &mut [u8]) -> Result<(), RangeOutOfBounds> {
+        let length = dest.len();
+        if self.len() != length {
+            return Err(RangeOutOfBounds {
+                required: (..length).into(),
+                bounds: (0, self.len()),
+            });
+        }
+        let n_copied = copy_io(dest, self.reader(), None);
+        debug_assert_eq!(n_copied, length);
+        Ok(())
+    }
+
+    /// Splits the buffer into two views at `mid`.
+    ///
+    /// The first view contains the bytes `..mid`, and the second one the
+    /// bytes `mid..`. This doesn't copy. Fails if `mid` is greater than the
+    /// buffer's length.
+    ///
+    /// This isn't called `split_at`, because [`ArcBufMut::split_at`] already
+    /// takes `&mut self` and splits off the buffer itself. Method resolution
+    /// picks a `&self` trait method before a `&mut self` inherent one, so a
+    /// `Buf::split_at` would silently replace it at every call site.
+    ///
+    /// [`ArcBufMut::split_at`]: arc_buf::ArcBufMut::split_at
+    #[inline]
+    fn split_view_at(
+        &self,
+        mid: usize,
+    ) -> Result<(Self::View<'_>, Self::View<'_>), RangeOutOfBounds> {
+        Ok((self.view(..mid)?, self.view(mid..)?))
+    }
+
+    /// Returns whether the buffer starts with `needle`.
+    ///
+    /// If `needle` is longer than the buffer, this returns `false`.
+    #[inline]
+    fn starts_with(&self, needle: &[u8]) -> bool {
+        self.view(..needle.len())
+            .is_ok_and(|view| buf_eq(view, needle))
+    }
+
+    /// Returns whether the buffer ends with `needle`.
+    ///
+    /// If `needle` is longer than the buffer, this returns `false`.
+    #[inline]
+    fn ends_with(&self, needle: &[u8]) -> bool {
+        self.len()
+            .checked_sub(needle.len())
+            .is_some_and(|start| self.view(start..).is_ok_and(|view| buf_eq(view, needle)))
+    }
+
+    /// Returns the offset of the first occurrence of `needle` in the buffer.
+    ///
+    /// This also finds occurrences that span chunk boundaries. An empty
+    /// `needle` is found at offset 0.
+    #[inline]
+    fn find(&self, needle: &[u8]) -> Option<usize> {
+        find_in_reader(self.reader(), needle)
+    }
+
+    /// Returns whether this buffer is stored in a single contiguous chunk.
+    ///
+    /// If this returns `true`, the buffer's reader will return the whole
+    /// buffer as a single chunk.
+    ///
+    /// # Default implementation
+    ///
+    /// The default implementation returns `true`. Buffers that can consist of
+    /// multiple chunks must override this.
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        true
+    }
+
+    /// Returns a [`Hexdump`] of the buffer, which implements
+    /// [`Display`](core::fmt::Display).
+    ///
+    /// This walks the buffer's chunks, so it also works for buffers that
+    /// aren't contiguous.
+    #[inline]
+    fn hexdump(&self) -> Hexdump<&Self> {
+        Hexdump::new(self)
+    }
+
+    /// Compares this buffer lexicographically with `other`.
+    ///
+    /// This walks both buffers chunk by chunk, so it also works for buffers
+    /// that aren't contiguous, and stops at the first differing byte. If one
+    /// buffer is a prefix of the other, the shorter one is less.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use byst::Buf;
+    /// assert_eq!(b"abc".cmp_prefix(&b"abd"), Ordering::Less);
+    /// assert_eq!(b"ab".cmp_prefix(&b"abc"), Ordering::Less);
+    /// ```
+    #[inline]
+    fn cmp_prefix(&self, other: &impl Buf) -> Ordering {
+        buf_cmp(self, other)
+    }
+
+    /// Returns the buffer's contents as owned [`Bytes`].
+    ///
+    /// # Default implementation
+    ///
+    /// The default implementation allocates a new buffer and copies the
+    /// buffer's chunks into it. Buffers that are already shared (e.g.
+    /// [`Bytes`] or [`ArcBuf`](arc_buf::ArcBuf)) override this to not copy.
+    fn to_bytes(&self) -> Bytes {
+        if self.is_empty() {
+            return Bytes::new();
+        }
+        let mut bytes = BytesMut::with_capacity(self.len());
+        for_each_chunk(&mut self.reader(), |chunk| bytes.extend_from_slice(chunk));
+        bytes.freeze()
     }
 }
 
@@ -82,14 +290,57 @@ pub trait BufExt: Buf {
     }
 
     fn as_vec(&self) -> Vec<u8> {
-        let mut reader = self.reader();
-        let mut buf = Vec::with_capacity(reader.remaining());
-        while let Some(chunk) = reader.peek_chunk() {
-            buf.extend(chunk.iter().copied());
-            reader.advance(chunk.len()).unwrap();
-        }
+        let mut buf = Vec::with_capacity(self.len());
+        for_each_chunk(&mut self.reader(), |chunk| buf.extend_from_slice(chunk));
         buf
     }
+
+    /// Computes the CRC-32 (IEEE) checksum of the buffer.
+    #[cfg(feature = "checksum")]
+    #[inline]
+    fn crc32(&self) -> u32 {
+        let mut crc32 = crate::checksum::Crc32::new();
+        crc32.update_buf(self);
+        crc32.finish()
+    }
+
+    /// Computes the Adler-32 checksum of the buffer.
+    #[cfg(feature = "checksum")]
+    #[inline]
+    fn adler32(&self) -> u32 {
+        let mut adler32 = crate::checksum::Adler32::new();
+        adler32.update_buf(self);
+        adler32.finish()
+    }
+
+    /// Computes the CRC-32 (IEEE) checksum of the buffer in parallel.
+    ///
+    /// This is only parallelized for contiguous buffers, see
+    /// [`parallel::crc32`](crate::parallel::crc32). Otherwise this is the
+    /// same as [`crc32`](Self::crc32).
+    #[cfg(feature = "rayon")]
+    fn par_crc32(&self) -> u32 {
+        let reader = self.reader();
+        match reader.peek_chunk() {
+            Some(chunk) if chunk.len() == self.len() => crate::parallel::crc32(chunk),
+            _ => self.crc32(),
+        }
+    }
+
+    /// Returns the offset of the first occurrence of `needle` in the buffer,
+    /// searching in parallel.
+    ///
+    /// This is only parallelized for contiguous buffers, see
+    /// [`parallel::find`](crate::parallel::find). Otherwise this is the same
+    /// as [`find`](Buf::find).
+    #[cfg(feature = "rayon")]
+    fn par_find(&self, needle: &[u8]) -> Option<usize> {
+        let reader = self.reader();
+        match reader.peek_chunk() {
+            Some(chunk) if chunk.len() == self.len() => crate::parallel::find(chunk, needle),
+            _ => self.find(needle),
+        }
+    }
 }
 
 impl<B: Buf> BufExt for B {}
@@ -113,6 +364,71 @@ pub trait BufMut: Buf {
     fn reserve(&mut self, size: usize) -> Result<(), Full>;
 
     fn size_limit(&self) -> SizeLimit;
+
+    /// Fills `dst` with the spare capacity after the end of the buffer, for
+    /// use with [`read_vectored`](std::io::Read::read_vectored).
+    ///
+    /// Returns the number of [`IoSliceMut`]s written to `dst`. After reading
+    /// into them, the buffer must be resized to include the bytes read (e.g.
+    /// with [`ArcBufMut::set_filled_to`](arc_buf::ArcBufMut::set_filled_to)).
+    ///
+    /// The slices only ever cover initialized memory, so they're safe to
+    /// hand to the OS. Buffers may initialize their spare capacity to do so.
+    ///
+    /// # Default implementation
+    ///
+    /// The default implementation doesn't expose any spare capacity and
+    /// returns 0.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn io_slices_mut<'a>(&'a mut self, _dst: &mut [IoSliceMut<'a>]) -> usize {
+        0
+    }
+
+    /// Appends `src` to the end of the buffer.
+    ///
+    /// This reserves space for all of `src` up front, and then copies it
+    /// chunk by chunk, so `src` doesn't need to be contiguous. This fails
+    /// without copying anything, if the buffer can't hold all of `src`.
+    fn put(&mut self, src: impl Buf) -> Result<(), Full>
+    where
+        Self: Sized,
+    {
+        let length = self.len();
+        let required = length.checked_add(src.len()).ok_or(Full {
+            required: usize::MAX,
+            capacity: length,
+        })?;
+        self.reserve(required)?;
+
+        let mut writer = self.writer();
+        BufWriter::advance(&mut writer, length)?;
+
+        try_for_each_chunk(&mut src.reader(), |chunk| {
+            BufWriter::extend(&mut writer, chunk)
+                .map_or_else(ControlFlow::Break, ControlFlow::Continue)
+        })
+        .map_or(Ok(()), |e| Err(e.into()))
+    }
+
+    /// Overwrites the buffer with `src`, which must have the same length.
+    ///
+    /// This walks the chunks of the buffer, so it doesn't need to be
+    /// contiguous. If the lengths differ, this fails without copying anything.
+    /// Use [`put`](Self::put) to append to the buffer instead.
+    #[inline]
+    fn copy_from_slice(&mut self, src: &[u8]) -> Result<(), Full>
+    where
+        Self: Sized,
+    {
+        if self.len() != src.len() {
+            return Err(Full {
+                required: src.len(),
+                capacity: self.len(),
+            });
+        }
+        copy(self, src)
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -236,6 +552,11 @@ macro_rules! impl_buf_with_deref {
                 fn reader(&self) -> Self::Reader<'_> {
                     <B as Buf>::reader(self.deref())
                 }
+
+                #[inline]
+                fn is_contiguous(&self) -> bool {
+                    <B as Buf>::is_contiguous(self.deref())
+                }
             }
         )*
     };
@@ -311,6 +632,12 @@ impl<'b, B: BufMut + ?Sized> BufMut for &'b mut B {
     fn size_limit(&self) -> SizeLimit {
         <B as BufMut>::size_limit(self)
     }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn io_slices_mut<'a>(&'a mut self, dst: &mut [IoSliceMut<'a>]) -> usize {
+        <B as BufMut>::io_slices_mut(self.deref_mut(), dst)
+    }
 }
 
 impl<B: BufMut + ?Sized> BufMut for Box<B> {
@@ -336,6 +663,12 @@ impl<B: BufMut + ?Sized> BufMut for Box<B> {
     fn size_limit(&self) -> SizeLimit {
         <B as BufMut>::size_limit(self)
     }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn io_slices_mut<'a>(&'a mut self, dst: &mut [IoSliceMut<'a>]) -> usize {
+        <B as BufMut>::io_slices_mut(self.deref_mut(), dst)
+    }
 }
 
 impl<'b> BufMut for &'b mut [u8] {
@@ -463,6 +796,31 @@ impl BufMut for Vec<u8> {
     fn size_limit(&self) -> SizeLimit {
         SizeLimit::Unlimited
     }
+
+    /// Exposes the spare capacity of the vector as a single [`IoSliceMut`].
+    ///
+    /// The spare capacity is zeroed first, so that it's initialized. After
+    /// reading `n` bytes into it, they can be included with
+    /// [`Vec::set_len`]. Use [`Vec::reserve`] to make room first.
+    #[cfg(feature = "std")]
+    fn io_slices_mut<'a>(&'a mut self, dst: &mut [IoSliceMut<'a>]) -> usize {
+        use core::mem::MaybeUninit;
+
+        let Some(slot) = dst.first_mut()
+        else {
+            return 0;
+        };
+        let spare = self.spare_capacity_mut();
+        if spare.is_empty() {
+            return 0;
+        }
+        MaybeUninit::fill(&mut *spare, 0);
+        *slot = IoSliceMut::new(unsafe {
+            // SAFETY: We just initialized the spare capacity.
+            MaybeUninit::slice_assume_init_mut(spare)
+        });
+        1
+    }
 }
 
 #[derive(Debug)]
@@ -548,7 +906,7 @@ impl<'v> BufWriter for VecWriter<'v> {
 
     #[inline]
     fn extend(&mut self, with: &[u8]) -> Result<(), crate::io::Full> {
-        let n_overwrite = std::cmp::min(self.vec.len() - self.position, with.len());
+        let n_overwrite = core::cmp::min(self.vec.len() - self.position, with.len());
         self.vec[self.position..][..n_overwrite].copy_from_slice(&with[..n_overwrite]);
         self.vec.extend(with[n_overwrite..].iter().copied());
         self.position += with.len();
@@ -614,7 +972,345 @@ pub(crate) mod tests {
     }
     pub(crate) use buf_mut_tests;
 
+    mod contains {
+        use crate::{
+            Buf,
+            Bytes,
+        };
+
+        #[test]
+        fn it_contains_ranges_in_bounds() {
+            let bytes = Bytes::from_static(b"Hello World");
+            assert!(bytes.contains(..));
+            assert!(bytes.contains(6..));
+            assert!(bytes.contains(0..11));
+            assert!(bytes.contains(11..));
+            assert!(bytes.contains(11..11));
+            assert!(Bytes::new().contains(0..0));
+        }
+
+        #[test]
+        fn it_doesnt_contain_ranges_out_of_bounds() {
+            let bytes = Bytes::from_static(b"Hello World");
+            assert!(!bytes.contains(0..12));
+            assert!(!bytes.contains(12..));
+            assert!(!bytes.contains(12..12));
+            assert!(!bytes.contains(..12));
+            assert!(!Bytes::new().contains(1..));
+        }
+    }
+
+    mod reader_from {
+        use crate::{
+            io::{
+                BufReader,
+                Seek,
+            },
+            Buf,
+            Bytes,
+        };
+
+        #[test]
+        fn it_resumes_at_position() {
+            let bytes = Bytes::from_static(b"Hello World");
+            let mut reader = bytes.reader();
+            BufReader::advance(&mut reader, 6).unwrap();
+            let position = reader.tell();
+            drop(reader);
+
+            let mut reader = bytes.reader_from(&position);
+            assert_eq!(reader.remaining(), 5);
+            assert_eq!(reader.rest(), b"World");
+        }
+
+        #[test]
+        fn it_resumes_slice_reader() {
+            let buf: &[u8] = b"Hello World";
+            let mut reader = buf.reader();
+            BufReader::advance(&mut reader, 6).unwrap();
+            let position = reader.tell();
+
+            let mut reader = buf.reader_from(&position);
+            assert_eq!(reader.rest(), b"World");
+        }
+    }
+
+    mod find {
+        use crate::{
+            Buf,
+            Bytes,
+        };
+
+        #[test]
+        fn it_finds_needle() {
+            let bytes = Bytes::from_static(b"\r\nHost: foo\r\n\r\n");
+            assert_eq!(bytes.find(b"\r\n"), Some(0));
+            assert_eq!(bytes.find(b"foo"), Some(8));
+            assert_eq!(bytes.find(b"\r\n\r\n"), Some(11));
+            assert_eq!(bytes.find(b"bar"), None);
+            assert_eq!(bytes.find(b"\r\n\r\n\r\n"), None);
+        }
+
+        #[test]
+        fn it_finds_empty_needle() {
+            assert_eq!(Bytes::from_static(b"Hello").find(b""), Some(0));
+            assert_eq!(Bytes::new().find(b""), Some(0));
+            assert_eq!(Bytes::new().find(b"a"), None);
+        }
+    }
+
+    mod starts_with {
+        use crate::{
+            buf::Buf,
+            Bytes,
+        };
+
+        #[test]
+        fn it_matches_magic_prefix() {
+            let png = Bytes::from_static(b"\x89PNG\r\n\x1a\n");
+            assert!(png.starts_with(b"\x89PNG"));
+            assert!(!png.starts_with(b"GIF8"));
+            assert!(png.starts_with(b""));
+            assert!(!Bytes::from_static(b"\x89PN").starts_with(b"\x89PNG"));
+        }
+
+        #[test]
+        fn it_matches_suffix() {
+            let buf = Bytes::from_static(b"Hello World");
+            assert!(buf.ends_with(b"World"));
+            assert!(!buf.ends_with(b"Hello"));
+            assert!(!buf.ends_with(b"Hello World!"));
+        }
+    }
+
+    mod to_bytes {
+        use crate::{
+            buf::{
+                arc_buf::ArcBufMut,
+                Buf,
+                Length,
+            },
+            util::counting_allocator::num_allocations,
+        };
+
+        #[test]
+        fn it_shares_arc_buf() {
+            let buf: ArcBufMut = b"Hello World".iter().copied().collect();
+            let buf = buf.freeze();
+
+            let bytes = buf.to_bytes();
+            assert_eq!(buf.ref_count().ref_count(), Some(2));
+            assert_eq!(bytes, b"Hello World");
+        }
+
+        #[test]
+        fn it_copies_slice() {
+            let data = b"Hello World".to_vec();
+
+            let before = num_allocations();
+            let bytes = (&data[..]).to_bytes();
+            assert!(num_allocations() > before);
+            drop(data);
+            assert_eq!(bytes, b"Hello World");
+        }
+
+        #[test]
+        fn it_doesnt_allocate_when_empty() {
+            let before = num_allocations();
+            let bytes = (&[] as &[u8]).to_bytes();
+            assert_eq!(num_allocations(), before);
+            assert!(bytes.is_empty());
+        }
+    }
+
+    mod copy_slice {
+        use crate::{
+            buf::{
+                arc_buf::ArcBufMut,
+                segments::Segments,
+                Buf,
+                BufMut,
+                Full,
+            },
+            Bytes,
+            RangeOutOfBounds,
+        };
+
+        #[test]
+        fn it_copies_to_slice() {
+            let bytes = Bytes::from(b"Hello World" as &'static [u8]);
+            let mut dest = [0; 11];
+            bytes.copy_to_slice(&mut dest).unwrap();
+            assert_eq!(&dest, b"Hello World");
+
+            let segments = Segments::new(&[b"Hello", b" ", b"World"]);
+            let mut dest = [0; 11];
+            segments.copy_to_slice(&mut dest).unwrap();
+            assert_eq!(&dest, b"Hello World");
+        }
+
+        #[test]
+        fn copy_to_slice_fails_if_lengths_differ() {
+            let bytes = Bytes::from(b"Hello" as &'static [u8]);
+
+            let mut dest = [0; 6];
+            assert_eq!(
+                bytes.copy_to_slice(&mut dest).unwrap_err(),
+                RangeOutOfBounds {
+                    required: (..6).into(),
+                    bounds: (0, 5),
+                }
+            );
+            assert_eq!(dest, [0; 6]);
+
+            let mut dest = [0; 4];
+            assert_eq!(
+                bytes.copy_to_slice(&mut dest).unwrap_err(),
+                RangeOutOfBounds {
+                    required: (..4).into(),
+                    bounds: (0, 5),
+                }
+            );
+            assert_eq!(dest, [0; 4]);
+        }
+
+        #[test]
+        fn it_copies_from_slice() {
+            let mut buf = ArcBufMut::new(8);
+            buf.put(b"Hello Wo").unwrap();
+            buf.copy_from_slice(b"Goodbye!").unwrap();
+            assert_eq!(buf, b"Goodbye!");
+        }
+
+        #[test]
+        fn copy_from_slice_fails_if_lengths_differ() {
+            let mut buf = ArcBufMut::new(8);
+            buf.put(b"Hello").unwrap();
+
+            assert_eq!(
+                buf.copy_from_slice(b"Hello!").unwrap_err(),
+                Full {
+                    required: 6,
+                    capacity: 5,
+                }
+            );
+            assert_eq!(
+                buf.copy_from_slice(b"Hey").unwrap_err(),
+                Full {
+                    required: 3,
+                    capacity: 5,
+                }
+            );
+            assert_eq!(buf, b"Hello");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod io_slices_mut {
+        use std::io::{
+            IoSliceMut,
+            Read,
+        };
+
+        use crate::{
+            buf::BufMut,
+            BytesMut,
+        };
+
+        #[test]
+        fn it_exposes_spare_capacity_of_vec() {
+            let mut buf = Vec::with_capacity(16);
+            buf.extend_from_slice(b"Hello");
+
+            let n_read = {
+                let mut slices = [IoSliceMut::new(&mut [])];
+                assert_eq!(buf.io_slices_mut(&mut slices), 1);
+                assert!(slices[0].len() >= 11);
+                (b" World" as &[u8]).read_vectored(&mut slices).unwrap()
+            };
+            unsafe {
+                // SAFETY: The spare capacity was initialized by `io_slices_mut`.
+                buf.set_len(5 + n_read);
+            }
+            assert_eq!(buf, b"Hello World");
+        }
+
+        #[test]
+        fn it_exposes_spare_capacity_of_bytes_mut() {
+            let mut buf = BytesMut::with_capacity(16);
+            buf.extend_from_slice(b"Hello");
+
+            let n_read = {
+                let mut slices = [IoSliceMut::new(&mut [])];
+                assert_eq!(buf.io_slices_mut(&mut slices), 1);
+                assert_eq!(slices[0].len(), 11);
+                (b" World" as &[u8]).read_vectored(&mut slices).unwrap()
+            };
+            buf.set_filled_to(5 + n_read);
+            assert_eq!(buf, b"Hello World");
+
+            let mut slices = [IoSliceMut::new(&mut [])];
+            assert_eq!(BytesMut::new().io_slices_mut(&mut slices), 0);
+        }
+    }
+
+    mod put {
+        use crate::{
+            buf::{
+                arc_buf::ArcBufMut,
+                BufMut,
+                Full,
+            },
+            Bytes,
+        };
+
+        #[test]
+        fn it_puts_bytes() {
+            let mut buf = ArcBufMut::new(16);
+            buf.put(Bytes::from_static(b"Hello")).unwrap();
+            buf.put(Bytes::from_static(b" World")).unwrap();
+            assert_eq!(buf, b"Hello World");
+        }
+
+        #[test]
+        fn it_puts_into_vec() {
+            let mut buf = b"Hello".to_vec();
+            buf.put(Bytes::from_static(b" World")).unwrap();
+            assert_eq!(buf, b"Hello World");
+        }
+
+        #[test]
+        fn put_fails_if_buffer_is_too_short() {
+            let mut buf = ArcBufMut::new(8);
+            buf.put(Bytes::from_static(b"Hello")).unwrap();
+            assert_eq!(
+                buf.put(Bytes::from_static(b" World")).unwrap_err(),
+                Full {
+                    required: 11,
+                    capacity: 8,
+                }
+            );
+            assert_eq!(buf, b"Hello");
+        }
+    }
+
     mod vec {
+        use crate::{
+            copy_io,
+            endianness::BigEndian,
+            io::WriterExt,
+            Bytes,
+        };
+
         buf_mut_tests!(Vec::<u8>::new());
+
+        #[test]
+        fn it_appends_when_used_as_writer() {
+            let bytes = Bytes::from(b"Hello" as &'static [u8]);
+            let mut vec = b"abc".to_vec();
+            assert_eq!(copy_io(&mut vec, bytes, None), 5);
+            (&mut vec).write_with(&0x12345678u32, BigEndian).unwrap();
+            assert_eq!(vec, b"abcHello\x12\x34\x56\x78");
+        }
     }
 }
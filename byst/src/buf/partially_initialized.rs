@@ -4,7 +4,7 @@
 //! uphold some invariants, can cause UB. We could make the `new` method unsafe,
 //! or use an unsafe-marked trait.
 
-use std::{
+use core::{
     fmt::Debug,
     mem::MaybeUninit,
 };
@@ -55,6 +55,11 @@ impl<B> PartiallyInitialized<B> {
         self.initialized = 0;
     }
 
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.initialized = self.initialized.min(len);
+    }
+
     #[inline]
     pub fn inner_ref(&self) -> &B {
         &self.buf
@@ -132,7 +137,7 @@ impl<B: AsRef<[MaybeUninit<u8>]> + AsMut<[MaybeUninit<u8>]>> AsMut<[u8]>
 
 impl<B: AsRef<[MaybeUninit<u8>]>> Debug for PartiallyInitialized<B> {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         debug_as_hexdump(f, self)
     }
 }
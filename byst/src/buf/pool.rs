@@ -0,0 +1,121 @@
+use std::sync::{
+    Mutex,
+    PoisonError,
+};
+
+use super::arc_buf::{
+    ArcBufMut,
+    Reclaim,
+};
+
+/// A pool of reusable buffers of a fixed size.
+///
+/// Buffers are checked out with [`BufferPool::checkout`]. Once all references
+/// to a checked out buffer are dropped, the pool will reclaim it on a later
+/// checkout, instead of allocating a new buffer.
+#[derive(Debug)]
+pub struct BufferPool {
+    buffer_size: usize,
+    reclaims: Mutex<Vec<Reclaim>>,
+}
+
+impl BufferPool {
+    /// Creates an empty pool for buffers with capacity `buffer_size`.
+    ///
+    /// This doesn't allocate any buffers yet.
+    #[inline]
+    pub fn new(buffer_size: usize) -> Self {
+        Self {
+            buffer_size,
+            reclaims: Mutex::new(vec![]),
+        }
+    }
+
+    /// Returns the capacity of the buffers in this pool.
+    #[inline]
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Returns the number of buffers tracked by this pool, both idle and
+    /// checked out.
+    #[inline]
+    pub fn num_buffers(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns an empty buffer.
+    ///
+    /// This reuses an idle buffer if there is one. Otherwise a new buffer is
+    /// allocated and tracked by the pool.
+    pub fn checkout(&self) -> ArcBufMut {
+        let mut reclaims = self.lock();
+
+        for reclaim in reclaims.iter() {
+            if let Some(buf) = reclaim.try_reclaim() {
+                return buf;
+            }
+        }
+
+        let (buf, reclaim) = ArcBufMut::new_reclaimable(self.buffer_size);
+        reclaims.push(reclaim);
+        buf
+    }
+
+    #[inline]
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Reclaim>> {
+        // a panic can't leave the list of reclaims in an inconsistent state, so we can
+        // ignore poisoning.
+        self.reclaims.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BufferPool;
+    use crate::{
+        buf::{
+            BufMut,
+            Length,
+        },
+        io::BufWriter,
+    };
+
+    #[test]
+    fn it_reuses_dropped_buffers() {
+        let pool = BufferPool::new(16);
+
+        let buf = pool.checkout();
+        assert_eq!(buf.capacity(), 16);
+        let ptr = buf.uninitialized().as_ptr();
+        drop(buf);
+
+        for _ in 0..4 {
+            let mut buf = pool.checkout();
+            assert_eq!(buf.uninitialized().as_ptr(), ptr);
+            assert!(buf.is_empty());
+            buf.writer().extend(b"Hello World").unwrap();
+        }
+        assert_eq!(pool.num_buffers(), 1);
+    }
+
+    #[test]
+    fn it_allocates_if_all_buffers_are_in_use() {
+        let pool = BufferPool::new(16);
+
+        let first = pool.checkout();
+        let second = pool.checkout();
+        assert_ne!(
+            first.uninitialized().as_ptr(),
+            second.uninitialized().as_ptr()
+        );
+        assert_eq!(pool.num_buffers(), 2);
+
+        let frozen = second.freeze();
+        drop(first);
+        let third = pool.checkout();
+        assert_eq!(pool.num_buffers(), 2);
+        drop(frozen);
+        drop(third);
+    }
+}
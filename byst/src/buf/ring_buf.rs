@@ -0,0 +1,485 @@
+use alloc::{
+    boxed::Box,
+    vec,
+};
+use core::fmt::Debug;
+
+use super::{
+    arc_buf::ArcBufMut,
+    BufReader,
+    BufWriter,
+    Length,
+};
+use crate::{
+    impl_me,
+    io::{
+        End,
+        Full,
+        Seek,
+    },
+    Buf,
+    BufMut,
+    Bytes,
+};
+
+/// A fixed-capacity circular buffer.
+///
+/// Bytes are written to the back of the buffer with the [`BufWriter`]
+/// implementation, and read from the front with the [`BufReader`]
+/// implementation. Writes wrap around at the end of the underlying buffer and
+/// the buffer never reallocates.
+///
+/// Since the contents might wrap around, [`BufReader::peek_chunk`] and
+/// [`BufWriter::peek_chunk_mut`] only return the contiguous portion up to the
+/// wrap point. Callers must loop to read or write across it.
+///
+/// # Example
+///
+/// ```
+/// # use byst::{buf::ring_buf::RingBuf, io::{BufReader, BufWriter}};
+/// let mut ring = RingBuf::new(8);
+///
+/// BufWriter::extend(&mut ring, b"Hello").unwrap();
+/// BufReader::advance(&mut ring, 4).unwrap();
+///
+/// // This wraps around the end of the underlying buffer.
+/// BufWriter::extend(&mut ring, b" World").unwrap();
+///
+/// assert_eq!(ring.remaining_mut(), 1);
+/// assert_eq!(ring.as_slices(), (b"o Wo" as &[u8], b"rld" as &[u8]));
+/// ```
+pub struct RingBuf {
+    buf: Box<[u8]>,
+
+    /// Index of the first filled byte.
+    start: usize,
+
+    /// Number of filled bytes.
+    len: usize,
+}
+
+impl RingBuf {
+    /// Creates a new [`RingBuf`] with the specified capacity.
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buf: vec![0; capacity].into_boxed_slice(),
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the capacity of the buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the number of filled bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of bytes that can still be written.
+    #[inline]
+    pub fn remaining_mut(&self) -> usize {
+        self.capacity() - self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Clears the buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// Returns the filled portion of the buffer as two slices. The second
+    /// slice is only non-empty if the contents wrap around.
+    pub fn as_slices(&self) -> (&[u8], &[u8]) {
+        let end = self.start + self.len;
+        if end <= self.capacity() {
+            (&self.buf[self.start..end], &[])
+        }
+        else {
+            (&self.buf[self.start..], &self.buf[..end - self.capacity()])
+        }
+    }
+
+    /// Wraps an index that is at most `2 * capacity` around.
+    #[inline]
+    fn wrap(&self, index: usize) -> usize {
+        if index >= self.capacity() {
+            index - self.capacity()
+        }
+        else {
+            index
+        }
+    }
+
+    /// Index at which the next byte will be written.
+    #[inline]
+    fn write_index(&self) -> usize {
+        self.wrap(self.start + self.len)
+    }
+
+    /// Returns the contiguous free portion of the buffer, starting at the write
+    /// index.
+    fn free_chunk_mut(&mut self) -> &mut [u8] {
+        if self.is_full() {
+            &mut []
+        }
+        else {
+            let write_index = self.write_index();
+            if write_index < self.start {
+                &mut self.buf[write_index..self.start]
+            }
+            else {
+                &mut self.buf[write_index..]
+            }
+        }
+    }
+
+    /// Copies the first `length` bytes into a [`Bytes`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length > self.len()`.
+    fn copy_to_bytes(&self, length: usize) -> Bytes {
+        assert!(length <= self.len);
+
+        let (first, second) = self.as_slices();
+        let n_first = core::cmp::min(first.len(), length);
+
+        let mut buf = ArcBufMut::new(length);
+        let mut writer = buf.writer();
+        writer
+            .extend(&first[..n_first])
+            .and_then(|()| writer.extend(&second[..length - n_first]))
+            .expect("Newly allocated buffer is too small");
+        buf.into()
+    }
+
+    #[inline]
+    fn end(&self, requested: usize) -> End {
+        End {
+            read: 0,
+            requested,
+            remaining: self.len,
+        }
+    }
+
+    #[inline]
+    fn full(&self, requested: usize) -> Full {
+        Full {
+            written: 0,
+            requested,
+            remaining: self.remaining_mut(),
+        }
+    }
+}
+
+impl Length for RingBuf {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Debug for RingBuf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (first, second) = self.as_slices();
+        f.debug_struct("RingBuf")
+            .field("capacity", &self.capacity())
+            .field("first", &first)
+            .field("second", &second)
+            .finish()
+    }
+}
+
+impl BufReader for RingBuf {
+    /// Views are copied, since the underlying buffer will be overwritten once
+    /// the bytes are consumed.
+    type View = Bytes;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        let (first, _) = self.as_slices();
+        (!first.is_empty()).then_some(first)
+    }
+
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        let view = self.peek_view(length)?;
+        BufReader::advance(self, length)?;
+        Ok(view)
+    }
+
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        if length <= self.len {
+            Ok(self.copy_to_bytes(length))
+        }
+        else {
+            Err(self.end(length))
+        }
+    }
+
+    #[inline]
+    fn rest(&mut self) -> Self::View {
+        let rest = self.peek_rest();
+        self.clear();
+        rest
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        self.copy_to_bytes(self.len)
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        if by <= self.len {
+            self.start = self.wrap(self.start + by);
+            self.len -= by;
+            Ok(())
+        }
+        else {
+            Err(self.end(by))
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len
+    }
+}
+
+/// Position in a [`RingBuf`].
+///
+/// Seeking back is only meaningful if nothing was written to the [`RingBuf`]
+/// in the meantime, since the bytes might have been overwritten.
+#[derive(Clone, Copy, Debug)]
+pub struct Position {
+    start: usize,
+    len: usize,
+}
+
+impl Seek for RingBuf {
+    type Position = Position;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        Position {
+            start: self.start,
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        let old = self.tell();
+        self.start = position.start;
+        self.len = position.len;
+        old
+    }
+}
+
+impl BufWriter for RingBuf {
+    type ViewMut<'a> = &'a mut [u8]
+    where
+        Self: 'a;
+
+    /// Returns the contiguous free portion of the buffer up to the wrap point.
+    #[inline]
+    fn peek_chunk_mut(&mut self) -> Option<&mut [u8]> {
+        let chunk = self.free_chunk_mut();
+        (!chunk.is_empty()).then_some(chunk)
+    }
+
+    /// Returns a view of the next `length` free bytes, and marks them as
+    /// filled.
+    ///
+    /// This fails if the free space doesn't contain `length` contiguous bytes.
+    fn view_mut(&mut self, length: usize) -> Result<Self::ViewMut<'_>, Full> {
+        if length <= self.free_chunk_mut().len() {
+            let write_index = self.write_index();
+            self.len += length;
+            Ok(&mut self.buf[write_index..][..length])
+        }
+        else {
+            Err(self.full(length))
+        }
+    }
+
+    /// Returns a view of the next `length` free bytes.
+    ///
+    /// This fails if the free space doesn't contain `length` contiguous bytes.
+    #[inline]
+    fn peek_view_mut(&mut self, length: usize) -> Result<Self::ViewMut<'_>, Full> {
+        let remaining = self.remaining_mut();
+        let chunk = self.free_chunk_mut();
+        if length <= chunk.len() {
+            Ok(&mut chunk[..length])
+        }
+        else {
+            Err(Full {
+                written: 0,
+                requested: length,
+                remaining,
+            })
+        }
+    }
+
+    /// Returns the contiguous free portion of the buffer up to the wrap point,
+    /// and marks it as filled.
+    #[inline]
+    fn rest_mut(&mut self) -> Self::ViewMut<'_> {
+        let write_index = self.write_index();
+        let length = self.free_chunk_mut().len();
+        self.len += length;
+        &mut self.buf[write_index..][..length]
+    }
+
+    /// Returns the contiguous free portion of the buffer up to the wrap point.
+    #[inline]
+    fn peek_rest_mut(&mut self) -> Self::ViewMut<'_> {
+        self.free_chunk_mut()
+    }
+
+    /// Marks the next `by` free bytes as filled.
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), Full> {
+        if by <= self.remaining_mut() {
+            self.len += by;
+            Ok(())
+        }
+        else {
+            Err(self.full(by))
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.remaining_mut()
+    }
+
+    fn extend(&mut self, with: &[u8]) -> Result<(), Full> {
+        if with.len() <= self.remaining_mut() {
+            let chunk = self.free_chunk_mut();
+            let n_first = core::cmp::min(chunk.len(), with.len());
+            chunk[..n_first].copy_from_slice(&with[..n_first]);
+            // if we wrapped around, the rest goes to the start of the buffer.
+            self.buf[..with.len() - n_first].copy_from_slice(&with[n_first..]);
+            self.len += with.len();
+            Ok(())
+        }
+        else {
+            Err(self.full(with.len()))
+        }
+    }
+}
+
+// note: unlike the `impl_me!` version, this checks that all of `buf` fits
+// before writing anything, so a full ring buffer isn't left with a partial
+// write.
+impl crate::io::Writer for RingBuf {
+    type Error = Full;
+
+    fn write_buf<B: Buf>(&mut self, buf: B) -> Result<(), Full> {
+        if buf.len() <= self.remaining_mut() {
+            let n_copied = crate::copy_io(&mut *self, buf.reader(), None);
+            assert_eq!(
+                n_copied,
+                buf.len(),
+                "Expected to copy {} bytes, but copied {n_copied}.",
+                buf.len()
+            );
+            Ok(())
+        }
+        else {
+            Err(self.full(buf.len()))
+        }
+    }
+
+    #[inline]
+    fn skip(&mut self, amount: usize) -> Result<(), Full> {
+        BufWriter::advance(self, amount)
+    }
+}
+
+impl_me! {
+    impl Reader for RingBuf as BufReader;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RingBuf;
+    use crate::io::{
+        BufReader,
+        BufWriter,
+        ReaderExt,
+        Writer,
+    };
+
+    #[test]
+    fn it_fills_and_drains() {
+        let mut ring = RingBuf::new(4);
+        assert!(ring.is_empty());
+
+        BufWriter::extend(&mut ring, b"abcd").unwrap();
+        assert!(ring.is_full());
+        assert_eq!(ring.remaining_mut(), 0);
+        assert!(BufWriter::extend(&mut ring, b"e").is_err());
+
+        assert_eq!(ring.read_array::<2>().unwrap(), *b"ab");
+        assert_eq!(BufReader::remaining(&ring), 2);
+        assert_eq!(ring.remaining_mut(), 2);
+        assert_eq!(ring.peek_chunk(), Some(b"cd" as &[u8]));
+    }
+
+    #[test]
+    fn it_writes_across_wrap_boundary() {
+        let mut ring = RingBuf::new(8);
+        ring.write_buf(b"Hello").unwrap();
+        BufReader::advance(&mut ring, 4).unwrap();
+
+        ring.write_buf(b" World").unwrap();
+        assert_eq!(ring.remaining_mut(), 1);
+        assert_eq!(ring.as_slices(), (b"o Wo" as &[u8], b"rld" as &[u8]));
+
+        // `peek_chunk` stops at the wrap point
+        assert_eq!(ring.peek_chunk(), Some(b"o Wo" as &[u8]));
+
+        // views straddling the wrap point are copied
+        let view = BufReader::view(&mut ring, 6).unwrap();
+        assert_eq!(view, b"o Worl");
+        assert_eq!(ring.read_array::<1>().unwrap(), *b"d");
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn peek_chunk_mut_stops_at_wrap_point() {
+        let mut ring = RingBuf::new(8);
+        ring.write_buf(b"abcdef").unwrap();
+        BufReader::advance(&mut ring, 4).unwrap();
+
+        assert_eq!(ring.peek_chunk_mut().unwrap().len(), 2);
+        BufWriter::advance(&mut ring, 2).unwrap();
+        assert_eq!(ring.peek_chunk_mut().unwrap().len(), 4);
+        assert_eq!(ring.remaining_mut(), 4);
+    }
+}
@@ -1,4 +1,5 @@
-use std::{
+use alloc::vec::Vec;
+use core::{
     cmp::Ordering,
     fmt::Debug,
 };
@@ -83,6 +84,15 @@ impl<B: Buf> Buf for Rope<B> {
     fn reader(&self) -> Self::Reader<'_> {
         todo!();
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        match &*self.segments {
+            [] => true,
+            [segment] => segment.buf.is_contiguous(),
+            _ => false,
+        }
+    }
 }
 
 impl<B: Length> Length for Rope<B> {
@@ -153,6 +163,15 @@ impl<'b, B: Buf> Buf for View<'b, B> {
     fn reader(&self) -> Self::Reader<'_> {
         todo!();
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        match self.segments {
+            [] => true,
+            [segment] => segment.buf.is_contiguous(),
+            _ => false,
+        }
+    }
 }
 
 impl<'b, B: Length> Length for View<'b, B> {
@@ -166,7 +185,7 @@ impl<'b, B: Length> Length for View<'b, B> {
 }
 
 pub struct Reader<'a, B> {
-    _segments: std::slice::Iter<'a, Segment<B>>,
+    _segments: core::slice::Iter<'a, Segment<B>>,
 }
 
 impl<'a, B> Default for Reader<'a, B> {
@@ -315,16 +334,15 @@ fn view_unchecked<B: Length>(segments: &[Segment<B>], start: usize, end: usize)
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::buf::BufExt;
+    use crate::{
+        buf::BufExt,
+        util::for_each_chunk,
+    };
 
     fn collect_chunks<B: Buf>(buf: B) -> Vec<Vec<u8>> {
         // uggh... fix this when we solved the BufReader lifetime issue.
-        let mut reader = buf.reader();
         let mut chunks = vec![];
-        while let Some(chunk) = reader.peek_chunk() {
-            chunks.push(chunk.to_owned());
-            reader.advance(chunk.len()).unwrap();
-        }
+        for_each_chunk(&mut buf.reader(), |chunk| chunks.push(chunk.to_owned()));
         chunks
     }
 
@@ -419,6 +437,16 @@ mod tests {
         assert_eq!(view2.as_vec(), b"World");
     }
 
+    #[test]
+    fn it_is_contiguous_only_with_one_segment() {
+        let mut rope = Rope::new();
+        assert!(rope.is_contiguous());
+        rope.push(b"Hello" as &[u8]);
+        assert!(rope.is_contiguous());
+        rope.push(b"World" as &[u8]);
+        assert!(!rope.is_contiguous());
+    }
+
     #[test]
     #[ignore = "Not yet implemented"]
     fn len_is_correct() {
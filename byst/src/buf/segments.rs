@@ -0,0 +1,298 @@
+use core::fmt::Debug;
+#[cfg(feature = "std")]
+use std::io::IoSlice;
+
+use super::{
+    Buf,
+    BufReader,
+    Length,
+};
+use crate::{
+    impl_me,
+    io::{
+        End,
+        Seek,
+    },
+    util::{
+        buf_eq,
+        debug_as_hexdump,
+    },
+    Range,
+    RangeOutOfBounds,
+};
+
+/// A buffer made up of borrowed segments, e.g. the output of a vectored read.
+///
+/// This presents the segments as a single buffer without copying them. It is
+/// also its own reader and view type. [`BufReader::peek_chunk`] returns the
+/// current segment, and reading a value that straddles segments (e.g. with
+/// [`ReaderExt::read_with`](crate::io::ReaderExt::read_with)) copies it
+/// together.
+///
+/// # Example
+///
+/// ```
+/// # use byst::{buf::segments::Segments, endianness::BigEndian, io::ReaderExt};
+/// let segments: &[&[u8]] = &[b"\x12\x34", b"\x56\x78"];
+/// let mut reader = Segments::new(segments);
+/// assert_eq!(reader.read_with::<u32, _>(BigEndian).unwrap(), 0x12345678);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Segments<'a> {
+    /// The current segment, with the bytes before the cursor removed.
+    first: &'a [u8],
+
+    /// The segments following `first`.
+    rest: &'a [&'a [u8]],
+
+    /// Number of bytes in this buffer. This might end in the middle of a
+    /// segment.
+    length: usize,
+}
+
+impl<'a> Segments<'a> {
+    /// Creates a buffer from `segments`.
+    pub fn new(segments: &'a [&'a [u8]]) -> Self {
+        let mut this = Self {
+            first: &[],
+            rest: segments,
+            length: segments.iter().map(|segment| segment.len()).sum(),
+        };
+        this.next_segment();
+        this
+    }
+
+    /// Makes sure that `first` isn't empty, unless the buffer is empty.
+    fn next_segment(&mut self) {
+        while self.first.is_empty() && self.length > 0 {
+            let Some((first, rest)) = self.rest.split_first()
+            else {
+                break;
+            };
+            self.first = first;
+            self.rest = rest;
+        }
+    }
+
+    #[inline]
+    fn chunk(&self) -> &'a [u8] {
+        &self.first[..self.first.len().min(self.length)]
+    }
+
+    fn advance_unchecked(&mut self, mut by: usize) {
+        while by > 0 {
+            let n = by.min(self.first.len());
+            self.first = &self.first[n..];
+            self.length -= n;
+            by -= n;
+            self.next_segment();
+        }
+    }
+
+    fn view_unchecked(&self, start: usize, end: usize) -> Self {
+        let mut view = *self;
+        view.advance_unchecked(start);
+        view.length = end - start;
+        view
+    }
+}
+
+impl<'a> Length for Segments<'a> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<'a> Buf for Segments<'a> {
+    type View<'b> = Segments<'a>
+    where
+        Self: 'b;
+
+    type Reader<'b> = Segments<'a>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn view(&self, range: impl Into<Range>) -> Result<Self::View<'_>, RangeOutOfBounds> {
+        let (start, end) = range.into().indices_checked_in(0, self.length)?;
+        Ok(self.view_unchecked(start, end))
+    }
+
+    #[inline]
+    fn reader(&self) -> Self::Reader<'_> {
+        *self
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.chunk().len() == self.length
+    }
+}
+
+impl<'a> BufReader for Segments<'a> {
+    type View = Self;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        (self.length > 0).then(|| self.chunk())
+    }
+
+    #[inline]
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        let view = self.peek_view(length)?;
+        self.advance_unchecked(length);
+        Ok(view)
+    }
+
+    #[inline]
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        if length <= self.length {
+            Ok(self.view_unchecked(0, length))
+        }
+        else {
+            Err(End {
+                read: 0,
+                requested: length,
+                remaining: self.length,
+            })
+        }
+    }
+
+    #[inline]
+    fn rest(&mut self) -> Self::View {
+        let rest = *self;
+        self.advance_unchecked(self.length);
+        rest
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        *self
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        if by <= self.length {
+            self.advance_unchecked(by);
+            Ok(())
+        }
+        else {
+            Err(End {
+                read: 0,
+                requested: by,
+                remaining: self.length,
+            })
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.length
+    }
+
+    #[cfg(feature = "std")]
+    fn peek_io_slices<'b>(&'b self, dst: &mut [IoSlice<'b>]) -> usize {
+        let mut segments = *self;
+        let mut n = 0;
+        for slot in dst {
+            if segments.length == 0 {
+                break;
+            }
+            let chunk = segments.chunk();
+            *slot = IoSlice::new(chunk);
+            segments.advance_unchecked(chunk.len());
+            n += 1;
+        }
+        n
+    }
+}
+
+impl<'a> Seek for Segments<'a> {
+    type Position = Self;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        *self
+    }
+
+    #[inline]
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        core::mem::replace(self, *position)
+    }
+}
+
+impl<'a> Debug for Segments<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        debug_as_hexdump(f, self)
+    }
+}
+
+impl<'a, T: Buf> PartialEq<T> for Segments<'a> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        buf_eq(self, other)
+    }
+}
+
+impl_me! {
+    impl['a] Reader for Segments<'a> as BufReader;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Segments;
+    use crate::{
+        buf::Length,
+        endianness::BigEndian,
+        io::{
+            BufReader,
+            ReaderExt,
+        },
+        Buf,
+    };
+
+    const SEGMENTS: &[&[u8]] = &[b"Hello", b"", b" ", b"World"];
+
+    #[test]
+    fn it_has_the_total_length() {
+        let buf = Segments::new(SEGMENTS);
+        assert_eq!(buf.len(), 11);
+        assert!(!buf.is_contiguous());
+        assert_eq!(buf, b"Hello World");
+        assert!(Segments::new(&[b"", b""]).is_empty());
+    }
+
+    #[test]
+    fn it_reads_value_straddling_segments() {
+        let segments: &[&[u8]] = &[b"\x12", b"\x34\x56", b"\x78\x9a"];
+        let mut reader = Segments::new(segments);
+        assert_eq!(reader.read_with::<u32, _>(BigEndian).unwrap(), 0x12345678);
+        assert_eq!(reader.peek_chunk(), Some(b"\x9a" as &[u8]));
+        assert!(reader.read_with::<u16, _>(BigEndian).is_err());
+    }
+
+    #[test]
+    fn it_walks_segments() {
+        let mut reader = Segments::new(SEGMENTS);
+        assert_eq!(reader.peek_chunk(), Some(b"Hello" as &[u8]));
+        reader.advance(3).unwrap();
+        assert_eq!(reader.peek_chunk(), Some(b"lo" as &[u8]));
+
+        let view = BufReader::view(&mut reader, 5).unwrap();
+        assert_eq!(view, b"lo Wo");
+        assert_eq!(reader.peek_chunk(), Some(b"rld" as &[u8]));
+        assert_eq!(reader.rest(), b"rld");
+        assert_eq!(reader.peek_chunk(), None);
+    }
+
+    #[test]
+    fn it_views_ranges() {
+        let buf = Segments::new(SEGMENTS);
+        let view = Buf::view(&buf, 4..7).unwrap();
+        assert_eq!(view, b"o W");
+        assert_eq!(view.reader().peek_chunk(), Some(b"o" as &[u8]));
+        assert!(Buf::view(&buf, 4..12).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use super::{
+    Buf,
+    BufWriter,
+    Empty,
+    Full,
+    Length,
+    SizeLimit,
+};
+use crate::{
+    impl_me,
+    BufMut,
+    Range,
+    RangeOutOfBounds,
+};
+
+/// A writer that discards everything written to it.
+///
+/// Unlike [`Empty`], which is a buffer with no capacity, writes to a [`Sink`]
+/// never fail. This is useful for dry-running serialization.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sink;
+
+impl Buf for Sink {
+    type View<'a> = Empty
+    where
+        Self: 'a;
+
+    type Reader<'a> = Empty
+    where
+        Self: 'a;
+
+    #[inline]
+    fn view(&self, range: impl Into<Range>) -> Result<Empty, RangeOutOfBounds> {
+        Empty.view(range)
+    }
+
+    #[inline]
+    fn reader(&self) -> Empty {
+        Empty
+    }
+}
+
+impl BufMut for Sink {
+    type ViewMut<'a> = Self
+    where
+        Self: 'a;
+
+    type Writer<'a> = Self
+    where
+        Self: 'a;
+
+    #[inline]
+    fn view_mut(&mut self, _range: impl Into<Range>) -> Result<Self, RangeOutOfBounds> {
+        Ok(Self)
+    }
+
+    #[inline]
+    fn writer(&mut self) -> Self {
+        Self
+    }
+
+    #[inline]
+    fn reserve(&mut self, _size: usize) -> Result<(), Full> {
+        Ok(())
+    }
+
+    #[inline]
+    fn size_limit(&self) -> SizeLimit {
+        SizeLimit::Unlimited
+    }
+}
+
+impl Length for Sink {
+    /// A [`Sink`] never contains anything, so this is always 0.
+    #[inline]
+    fn len(&self) -> usize {
+        0
+    }
+}
+
+impl BufWriter for Sink {
+    type ViewMut<'a> = Self where Self: 'a;
+
+    #[inline]
+    fn peek_chunk_mut(&mut self) -> Option<&mut [u8]> {
+        None
+    }
+
+    #[inline]
+    fn view_mut(&mut self, _length: usize) -> Result<Self::ViewMut<'_>, crate::io::Full> {
+        Ok(Self)
+    }
+
+    #[inline]
+    fn peek_view_mut(&mut self, _length: usize) -> Result<Self::ViewMut<'_>, crate::io::Full> {
+        Ok(Self)
+    }
+
+    #[inline]
+    fn rest_mut(&mut self) -> Self::ViewMut<'_> {
+        Self
+    }
+
+    #[inline]
+    fn peek_rest_mut(&mut self) -> Self::ViewMut<'_> {
+        Self
+    }
+
+    #[inline]
+    fn advance(&mut self, _by: usize) -> Result<(), crate::io::Full> {
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        usize::MAX
+    }
+
+    #[inline]
+    fn extend(&mut self, _with: &[u8]) -> Result<(), crate::io::Full> {
+        Ok(())
+    }
+
+    #[inline]
+    fn fill(&mut self, _byte: u8, _count: usize) -> Result<(), crate::io::Full> {
+        Ok(())
+    }
+}
+
+impl_me! {
+    impl Writer for Sink as BufWriter;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sink;
+    use crate::{
+        buf::{
+            BufMut,
+            Empty,
+        },
+        endianness::NetworkEndian,
+        io::{
+            BufWriter,
+            WriterExt,
+        },
+    };
+
+    #[test]
+    fn sink_discards_writes() {
+        let mut sink = Sink;
+        BufWriter::extend(&mut sink, &[0xab; 1024]).unwrap();
+        BufWriter::fill(&mut sink, 0, 1024).unwrap();
+        sink.write_with(&0x12345678u32, NetworkEndian).unwrap();
+        assert_eq!(BufWriter::remaining(&sink), usize::MAX);
+        assert!(sink.put([0xab; 1024]).is_ok());
+
+        let mut empty = Empty;
+        assert!(BufWriter::extend(&mut empty, &[0xab; 1024]).is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::buf::arc_buf::{
     ArcBufMut,
     Reclaim,
@@ -1,13 +1,39 @@
-use std::fmt::Debug;
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{
+        FromUtf8Error,
+        String,
+    },
+    vec::Vec,
+};
+use core::{
+    fmt::{
+        Debug,
+        Display,
+        LowerHex,
+        UpperHex,
+        Write,
+    },
+    ops::{
+        Index,
+        RangeBounds,
+    },
+    str::Utf8Error,
+};
 
 use super::{
     r#impl::BytesImpl,
-    r#static::Static,
     view::View,
+    BytesMut,
 };
 use crate::{
     buf::{
-        Empty,
+        arc_buf::{
+            ArcBufMut,
+            RefCount,
+        },
+        BufExt,
         Length,
     },
     impl_me,
@@ -20,6 +46,8 @@ use crate::{
         buf_eq,
         cfg_pub,
         debug_as_hexdump,
+        fmt_hex,
+        for_each_chunk,
     },
     Buf,
     Range,
@@ -32,16 +60,73 @@ pub struct Bytes {
 }
 
 impl Bytes {
+    /// An empty [`Bytes`].
+    pub const EMPTY: Self = Self::from_static(&[]);
+
     /// Creates an empty [`Bytes`].
     ///
     /// This doesn't allocate.
     #[inline]
-    pub fn new() -> Self {
-        // note: this really doesn't allocate, since [`Empty`] is a ZST, and a `dyn ZST`
-        // is ZST itself.[1]
-        //
-        // [1]: https://users.rust-lang.org/t/what-does-box-dyn-actually-allocate/56618/2
-        Self::from_impl(Box::new(Empty))
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Creates a [`Bytes`] from a static slice.
+    ///
+    /// This doesn't allocate, and can be used in `const` contexts.
+    #[inline]
+    pub const fn from_static(bytes: &'static [u8]) -> Self {
+        Self {
+            inner: View::from_slice(bytes),
+        }
+    }
+
+    /// Creates a [`Bytes`] that contains `pattern` repeated `count` times.
+    ///
+    /// This allocates the whole buffer once. If `pattern` is empty or `count`
+    /// is 0, this doesn't allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the buffer would overflow.
+    pub fn repeat(pattern: &[u8], count: usize) -> Self {
+        let length = pattern
+            .len()
+            .checked_mul(count)
+            .expect("Bytes::repeat: capacity overflow");
+        if length == 0 {
+            return Self::new();
+        }
+
+        let mut buf = ArcBufMut::new(length);
+        buf.fully_initialize();
+        let data = &mut buf.initialized_mut()[..length];
+        data[..pattern.len()].copy_from_slice(pattern);
+
+        // double the repeated portion, until the buffer is full.
+        let mut filled = pattern.len();
+        while filled < length {
+            let n = core::cmp::min(filled, length - filled);
+            data.copy_within(..n, filled);
+            filled += n;
+        }
+
+        buf.set_filled_to(length);
+        buf.freeze().into()
+    }
+
+    /// Copies `data` into a new buffer. If `data` is empty, this doesn't
+    /// allocate.
+    fn copy_from_slice(data: &[u8]) -> Self {
+        if data.is_empty() {
+            return Self::new();
+        }
+
+        let mut buf = ArcBufMut::new(data.len());
+        buf.fully_initialize();
+        buf.initialized_mut()[..data.len()].copy_from_slice(data);
+        buf.set_filled_to(data.len());
+        buf.freeze().into()
     }
 
     cfg_pub! {
@@ -50,6 +135,218 @@ impl Bytes {
             View::from_impl(inner).into()
         }
     }
+
+    /// Shortens the [`Bytes`] to the first `len` bytes.
+    ///
+    /// If `len` is greater or equal to the current length, this has no effect.
+    /// This doesn't copy. The underlying buffer is only released, if the
+    /// [`Bytes`] becomes empty.
+    pub fn truncate(&mut self, len: usize) {
+        if len == 0 {
+            self.clear();
+        }
+        else if len < self.len() {
+            self.inner = Buf::view(&self.inner, ..len).expect("Range is within bounds");
+        }
+    }
+
+    /// Returns a portion of the [`Bytes`].
+    ///
+    /// This doesn't copy. Unlike [`Buf::view`] this takes any
+    /// [`RangeBounds`], and panics instead of returning an error, like
+    /// `bytes::Bytes::slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    #[inline]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Bytes {
+        Buf::view(self, Range::from_range_bounds(range)).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Clears the [`Bytes`], releasing the underlying buffer.
+    ///
+    /// This doesn't allocate.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Adopts the vector of a [`std::io::Cursor`] (see
+    /// [`Bytes::from<Vec<u8>>`]), and returns it together with the cursor's
+    /// position.
+    ///
+    /// This is the inverse of [`into_std_cursor`](Self::into_std_cursor).
+    /// The position is returned separately, since [`Bytes`] doesn't have one.
+    #[cfg(feature = "std")]
+    pub fn from_std_cursor(cursor: std::io::Cursor<Vec<u8>>) -> (Self, u64) {
+        let position = cursor.position();
+        (Self::from(cursor.into_inner()), position)
+    }
+
+    /// Copies the bytes into a [`std::io::Cursor`] positioned at `position`.
+    ///
+    /// This is useful to pass the bytes to code that expects a
+    /// [`std::io::Read`] or [`std::io::Seek`] implementation.
+    #[cfg(feature = "std")]
+    pub fn into_std_cursor(self, position: u64) -> std::io::Cursor<Vec<u8>> {
+        let mut cursor = std::io::Cursor::new(self.as_vec());
+        cursor.set_position(position);
+        cursor
+    }
+
+    /// Returns the reference count of the underlying buffer.
+    ///
+    /// This is [`RefCount::Static`] for buffers that aren't
+    /// reference-counted, e.g. static slices.
+    #[inline]
+    pub fn ref_count(&self) -> RefCount {
+        self.inner.ref_count()
+    }
+
+    /// Returns `true` if this is the only reference to the underlying buffer.
+    ///
+    /// This is the same check that [`try_into_mut`](Self::try_into_mut)
+    /// does. It's `false` for buffers that aren't reference-counted, and if
+    /// there are [`WeakBuf`](crate::buf::arc_buf::WeakBuf)s to the buffer.
+    #[inline]
+    pub fn is_unique(&self) -> bool {
+        self.inner.is_unique()
+    }
+
+    /// Converts into a [`BytesMut`] without copying, if this is the only
+    /// reference to the underlying buffer.
+    ///
+    /// If the buffer is shared, or not reference-counted (e.g. a static
+    /// slice), `self` is returned unchanged.
+    #[inline]
+    pub fn try_into_mut(self) -> Result<BytesMut, Bytes> {
+        self.inner
+            .try_into_mut()
+            .map(BytesMut::from)
+            .map_err(Self::from)
+    }
+
+    /// Returns the bytes as a slice, if they're stored in a single contiguous
+    /// chunk.
+    ///
+    /// Use this to choose between a fast path for contiguous buffers and
+    /// walking the chunks with a reader.
+    #[inline]
+    pub fn contiguous(&self) -> Option<&[u8]> {
+        match BufReader::peek_chunk(self) {
+            None => self.is_empty().then_some(&[]),
+            Some(chunk) => (chunk.len() == self.len()).then_some(chunk),
+        }
+    }
+
+    /// Returns the bytes as a string slice, if they're valid UTF-8.
+    ///
+    /// This never copies. The error tells where validation failed (see
+    /// [`Utf8Error::valid_up_to`]). Use [`to_str`](Self::to_str) if the bytes
+    /// might not be contiguous, or [`String::try_from`] to get an owned
+    /// [`String`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes are not stored in a single contiguous chunk (see
+    /// [`Bytes::contiguous`]).
+    #[inline]
+    pub fn as_str(&self) -> Result<&str, Utf8Error> {
+        core::str::from_utf8(self.expect_contiguous())
+    }
+
+    /// Returns the bytes as a string slice, without checking that they're
+    /// valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8 (see [`core::str::from_utf8_unchecked`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes are not stored in a single contiguous chunk (see
+    /// [`Bytes::contiguous`]).
+    #[inline]
+    pub unsafe fn as_str_unchecked(&self) -> &str {
+        core::str::from_utf8_unchecked(self.expect_contiguous())
+    }
+
+    /// Returns the bytes as a string, if they're valid UTF-8.
+    ///
+    /// Unlike [`as_str`](Self::as_str), this also works if the bytes are not
+    /// stored in a single contiguous chunk, in which case they're copied into
+    /// an owned [`String`].
+    pub fn to_str(&self) -> Result<Cow<'_, str>, Utf8Error> {
+        match self.contiguous() {
+            Some(bytes) => core::str::from_utf8(bytes).map(Cow::Borrowed),
+            None => {
+                String::from_utf8(self.as_vec())
+                    .map(Cow::Owned)
+                    .map_err(|e| e.utf8_error())
+            }
+        }
+    }
+
+    fn expect_contiguous(&self) -> &[u8] {
+        self.contiguous()
+            .expect("the bytes are not stored in a single contiguous chunk")
+    }
+
+    /// Returns a wrapper that displays the bytes on a single line.
+    ///
+    /// Printable ASCII characters are displayed as is, and all other bytes are
+    /// escaped as `\xNN`. This is more compact than the hexdump used by the
+    /// [`Debug`] implementation.
+    #[inline]
+    pub fn display(&self) -> BytesDisplay<'_> {
+        BytesDisplay { bytes: self }
+    }
+
+    /// Returns the bytes as a lowercase hex string.
+    pub fn to_hex_string(&self) -> String {
+        let mut hex = String::with_capacity(2 * self.len());
+        for_each_chunk(&mut self.reader(), |chunk| {
+            for byte in chunk {
+                write!(hex, "{byte:02x}").expect("Writing to a String failed");
+            }
+        });
+        hex
+    }
+}
+
+/// Displays [`Bytes`] on a single line, escaping non-printable bytes.
+///
+/// This is returned by [`Bytes::display`].
+#[derive(Clone, Copy)]
+pub struct BytesDisplay<'a> {
+    bytes: &'a Bytes,
+}
+
+impl<'a> Display for BytesDisplay<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut result = Ok(());
+        for_each_chunk(&mut self.bytes.reader(), |chunk| {
+            for &byte in chunk {
+                if result.is_err() {
+                    return;
+                }
+                result = match byte {
+                    b'\\' => f.write_str("\\\\"),
+                    b' '..=b'~' => f.write_char(byte.into()),
+                    _ => write!(f, "\\x{byte:02x}"),
+                };
+            }
+        });
+        result
+    }
+}
+
+impl<'a> Debug for BytesDisplay<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "b\"{self}\"")
+    }
 }
 
 impl From<View<'static>> for Bytes {
@@ -71,11 +368,63 @@ impl Default for Bytes {
 
 impl Debug for Bytes {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         debug_as_hexdump(f, self)
     }
 }
 
+/// All byte values, so that [`Bytes`] can be indexed without being contiguous.
+static BYTE_VALUES: [u8; 256] = {
+    let mut values = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        values[i] = i as u8;
+        i += 1;
+    }
+    values
+};
+
+impl Index<usize> for Bytes {
+    type Output = u8;
+
+    /// Returns the byte at `index`.
+    ///
+    /// This works for bytes that are not stored in a single contiguous chunk
+    /// too. In that case, the returned reference doesn't point into the
+    /// buffer, but to an equal byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        if let Some(chunk) = self.contiguous() {
+            return &chunk[index];
+        }
+        let byte = self.get(index).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len()
+            )
+        });
+        &BYTE_VALUES[usize::from(byte)]
+    }
+}
+
+impl LowerHex for Bytes {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_hex(f, self, false)
+    }
+}
+
+impl UpperHex for Bytes {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_hex(f, self, true)
+    }
+}
+
 impl<R: Buf> PartialEq<R> for Bytes {
     #[inline]
     fn eq(&self, other: &R) -> bool {
@@ -83,10 +432,93 @@ impl<R: Buf> PartialEq<R> for Bytes {
     }
 }
 
+impl PartialEq<str> for Bytes {
+    /// Compares the bytes to the UTF-8 encoding of the string.
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        buf_eq(self, other.as_bytes())
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Bytes {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        buf_eq(self, other.as_bytes())
+    }
+}
+
+impl PartialEq<String> for Bytes {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        buf_eq(self, other.as_bytes())
+    }
+}
+
+impl PartialEq<Bytes> for str {
+    #[inline]
+    fn eq(&self, other: &Bytes) -> bool {
+        other == self
+    }
+}
+
+impl<'a> PartialEq<Bytes> for &'a str {
+    #[inline]
+    fn eq(&self, other: &Bytes) -> bool {
+        other == *self
+    }
+}
+
+impl PartialEq<Bytes> for String {
+    #[inline]
+    fn eq(&self, other: &Bytes) -> bool {
+        other == self
+    }
+}
+
+impl TryFrom<Bytes> for String {
+    type Error = FromUtf8Error;
+
+    /// Copies the bytes into a [`String`], if they're valid UTF-8.
+    ///
+    /// On failure, [`FromUtf8Error::utf8_error`] tells where the bytes stopped
+    /// being valid UTF-8.
+    #[inline]
+    fn try_from(value: Bytes) -> Result<Self, Self::Error> {
+        String::from_utf8(value.as_vec())
+    }
+}
+
 impl From<&'static [u8]> for Bytes {
     #[inline]
     fn from(value: &'static [u8]) -> Self {
-        Self::from_impl(Box::new(Static(value)))
+        Self::from_static(value)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Bytes {
+    /// Copies the array into a new buffer.
+    ///
+    /// If `N` is 0, this doesn't allocate.
+    #[inline]
+    fn from(value: [u8; N]) -> Self {
+        Self::copy_from_slice(&value)
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    /// Adopts the vector's allocation.
+    ///
+    /// This doesn't copy, unless the vector has spare capacity, in which case
+    /// [`Vec::into_boxed_slice`] reallocates it. If the vector is empty, this
+    /// doesn't allocate.
+    #[inline]
+    fn from(value: Vec<u8>) -> Self {
+        if value.is_empty() {
+            Self::new()
+        }
+        else {
+            ArcBufMut::from(value.into_boxed_slice()).into()
+        }
     }
 }
 
@@ -108,6 +540,16 @@ impl Buf for Bytes {
     fn reader(&self) -> Self::Reader<'_> {
         self.clone()
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.inner.is_contiguous()
+    }
+
+    #[inline]
+    fn to_bytes(&self) -> Bytes {
+        self.clone()
+    }
 }
 
 impl BufReader for Bytes {
@@ -180,3 +622,370 @@ impl_me! {
     impl Reader for Bytes as BufReader;
     impl Read<_, ()> for Bytes as BufReader::View;
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        borrow::Cow,
+        boxed::Box,
+    };
+
+    use super::Bytes;
+    use crate::{
+        buf::{
+            arc_buf::ArcBufMut,
+            segments::Segments,
+            Buf,
+            Length,
+        },
+        bytes::{
+            r#impl as bytes_impl,
+            BytesMut,
+        },
+        io::{
+            BufReader,
+            End,
+        },
+        util::counting_allocator::num_allocations,
+        Range,
+        RangeOutOfBounds,
+    };
+
+    /// Backs [`Bytes`] with [`Segments`], to test bytes that are not stored in
+    /// a single contiguous chunk.
+    #[derive(Clone)]
+    struct Segmented(Segments<'static>);
+
+    impl Length for Segmented {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    // note: `BytesImpl` isn't imported, since its `clone` would be ambiguous
+    // for buffers that implement it.
+    impl bytes_impl::BytesImpl<'static> for Segmented {
+        fn clone(&self) -> Box<dyn bytes_impl::BytesImpl<'static>> {
+            Box::new(Clone::clone(self))
+        }
+
+        fn peek_chunk(&self) -> Option<&[u8]> {
+            BufReader::peek_chunk(&self.0)
+        }
+
+        fn view(
+            &self,
+            range: Range,
+        ) -> Result<Box<dyn bytes_impl::BytesImpl<'static>>, RangeOutOfBounds> {
+            Ok(Box::new(Segmented(Buf::view(&self.0, range)?)))
+        }
+
+        fn advance(&mut self, by: usize) -> Result<(), End> {
+            BufReader::advance(&mut self.0, by)
+        }
+
+        fn try_into_mut(
+            self: Box<Self>,
+        ) -> Result<
+            Box<dyn bytes_impl::BytesMutImpl<'static>>,
+            Box<dyn bytes_impl::BytesImpl<'static>>,
+        > {
+            Err(self)
+        }
+    }
+
+    fn segmented(segments: &'static [&'static [u8]]) -> Bytes {
+        Bytes::from_impl(Box::new(Segmented(Segments::new(segments))))
+    }
+
+    #[test]
+    fn truncate_shares_buffer_until_cleared() {
+        let buf: ArcBufMut = b"Hello World".iter().copied().collect();
+        let buf = buf.freeze();
+        let mut bytes = Bytes::from(buf.clone());
+        assert_eq!(buf.ref_count().ref_count(), Some(2));
+
+        bytes.truncate(5);
+        assert_eq!(bytes, b"Hello");
+        assert_eq!(buf.ref_count().ref_count(), Some(2));
+
+        bytes.truncate(10);
+        assert_eq!(bytes.len(), 5);
+
+        bytes.clear();
+        assert!(bytes.is_empty());
+        assert_eq!(buf.ref_count().ref_count(), Some(1));
+    }
+
+    #[test]
+    fn single_chunk_bytes_are_contiguous() {
+        let bytes = Bytes::from(b"Hello" as &'static [u8]);
+        assert!(bytes.is_contiguous());
+        assert_eq!(bytes.contiguous(), Some(b"Hello" as &[u8]));
+
+        let bytes = Bytes::new();
+        assert!(bytes.is_contiguous());
+        assert_eq!(bytes.contiguous(), Some(b"" as &[u8]));
+    }
+
+    #[test]
+    fn static_bytes_dont_allocate() {
+        const HELLO: Bytes = Bytes::from_static(b"Hello World");
+
+        let before = num_allocations();
+        let mut bytes = HELLO;
+        let view = Buf::view(&bytes, 6..).unwrap();
+        BufReader::advance(&mut bytes, 6).unwrap();
+        let cloned = bytes.clone();
+        let empty = Bytes::EMPTY;
+        assert_eq!(num_allocations(), before);
+
+        assert_eq!(view, b"World");
+        assert_eq!(cloned, b"World");
+        assert!(empty.is_empty());
+        assert_eq!(bytes.contiguous(), Some(b"World" as &[u8]));
+    }
+
+    #[test]
+    fn it_displays_escaped_bytes() {
+        let bytes = Bytes::from(b"Hi\x00\n \\\xff!" as &'static [u8]);
+        assert_eq!(bytes.display().to_string(), r"Hi\x00\x0a \\\xff!");
+        assert_eq!(bytes.to_hex_string(), "4869000a205cff21");
+    }
+
+    #[test]
+    fn split_view_at_shares_buffer() {
+        let buf: ArcBufMut = b"Hello World".iter().copied().collect();
+        let buf = buf.freeze();
+        let bytes = Bytes::from(buf.clone());
+
+        let (left, right) = bytes.split_view_at(5).unwrap();
+        assert_eq!(left, b"Hello");
+        assert_eq!(right, b" World");
+        assert_eq!(buf.ref_count().ref_count(), Some(4));
+
+        let (left, right) = bytes.split_view_at(11).unwrap();
+        assert_eq!(left, b"Hello World");
+        assert!(right.is_empty());
+
+        assert!(bytes.split_view_at(12).is_err());
+    }
+
+    #[test]
+    fn it_reports_ref_count() {
+        let buf: ArcBufMut = b"Hello World".iter().copied().collect();
+        let bytes = Bytes::from(buf);
+        assert_eq!(bytes.ref_count().ref_count(), Some(1));
+        assert!(bytes.is_unique());
+
+        let clone = bytes.clone();
+        assert_eq!(bytes.ref_count().ref_count(), Some(2));
+        assert!(!bytes.is_unique());
+
+        drop(clone);
+        assert_eq!(bytes.ref_count().ref_count(), Some(1));
+        assert!(bytes.is_unique());
+
+        let bytes = Bytes::from_static(b"Hello World");
+        assert!(bytes.ref_count().is_static());
+        assert!(!bytes.is_unique());
+    }
+
+    #[test]
+    fn it_isnt_unique_with_weak_refs() {
+        let buf: ArcBufMut = b"Hello World".iter().copied().collect();
+        let buf = buf.freeze();
+        let weak = buf.downgrade();
+        let bytes = Bytes::from(buf);
+        assert!(bytes.ref_count().is_unique());
+        assert!(!bytes.is_unique());
+        let bytes = bytes.try_into_mut().unwrap_err();
+
+        drop(weak);
+        assert!(bytes.is_unique());
+        assert!(bytes.try_into_mut().is_ok());
+    }
+
+    #[test]
+    fn try_into_mut_succeeds_if_unique() {
+        let buf: ArcBufMut = b"Hello World".iter().copied().collect();
+        let bytes = Bytes::from(buf);
+
+        let clone = bytes.clone();
+        let bytes = bytes.try_into_mut().unwrap_err();
+        assert_eq!(bytes, b"Hello World");
+        drop(clone);
+
+        let mut bytes_mut = bytes.try_into_mut().unwrap();
+        bytes_mut.extend_from_slice(b"!");
+        assert_eq!(bytes_mut, b"Hello World!");
+
+        let bytes = Bytes::from_static(b"Hello World");
+        assert_eq!(bytes.try_into_mut().unwrap_err(), b"Hello World");
+    }
+
+    #[test]
+    fn it_slices() {
+        let bytes = Bytes::from_static(b"Hello World");
+        assert_eq!(bytes.slice(2..5), b"llo");
+        assert_eq!(bytes.slice(..3), b"Hel");
+        assert_eq!(bytes.slice(4..), b"o World");
+        assert_eq!(bytes.slice(..), b"Hello World");
+        assert!(bytes.slice(11..).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_panics_if_out_of_bounds() {
+        Bytes::from_static(b"Hello World").slice(4..12);
+    }
+
+    #[test]
+    fn it_repeats_pattern() {
+        assert_eq!(Bytes::repeat(b"ab", 3), b"ababab");
+        assert_eq!(Bytes::repeat(b"abc", 1), b"abc");
+        assert_eq!(Bytes::repeat(b"x", 5), b"xxxxx");
+        assert!(Bytes::repeat(b"ab", 0).is_empty());
+        assert!(Bytes::repeat(b"", 3).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn repeat_panics_on_overflow() {
+        Bytes::repeat(b"ab", usize::MAX);
+    }
+
+    #[test]
+    fn it_gets_bytes_at_index() {
+        let bytes = Bytes::from_static(b"Hello");
+        assert_eq!(bytes.get(0), Some(b'H'));
+        assert_eq!(bytes.get(4), Some(b'o'));
+        assert_eq!(bytes.get(5), None);
+        assert_eq!(Bytes::new().get(0), None);
+    }
+
+    #[test]
+    fn it_indexes_bytes() {
+        let bytes = Bytes::from_static(b"Hello");
+        assert_eq!(bytes[0], b'H');
+        assert_eq!(bytes[4], b'o');
+
+        let bytes = segmented(&[b"He", b"llo"]);
+        assert!(bytes.contiguous().is_none());
+        assert_eq!(bytes[1], b'e');
+        assert_eq!(bytes[2], b'l');
+        assert_eq!(bytes[4], b'o');
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_on_index_out_of_bounds() {
+        let _ = Bytes::from_static(b"Hello")[5];
+    }
+
+    #[test]
+    fn it_converts_to_string() {
+        let bytes = Bytes::from_static("Grüße".as_bytes());
+        assert_eq!(bytes.as_str().unwrap(), "Grüße");
+        assert_eq!(unsafe { bytes.as_str_unchecked() }, "Grüße");
+        assert!(matches!(bytes.to_str().unwrap(), Cow::Borrowed("Grüße")));
+        assert_eq!(String::try_from(bytes).unwrap(), "Grüße");
+
+        let bytes = Bytes::from_static(b"Hello \xc3\x28 World");
+        assert_eq!(bytes.as_str().unwrap_err().valid_up_to(), 6);
+        assert_eq!(bytes.to_str().unwrap_err().valid_up_to(), 6);
+        let e = String::try_from(bytes).unwrap_err();
+        assert_eq!(e.utf8_error().valid_up_to(), 6);
+        assert_eq!(e.utf8_error().error_len(), Some(1));
+    }
+
+    #[test]
+    fn it_copies_non_contiguous_bytes_to_string() {
+        let bytes = segmented(&[b"Gr\xc3", b"\xbc\xc3\x9fe"]);
+        assert!(matches!(bytes.to_str().unwrap(), Cow::Owned(s) if s == "Grüße"));
+
+        let bytes = segmented(&[b"Hello ", b"\xc3\x28 World"]);
+        assert_eq!(bytes.to_str().unwrap_err().valid_up_to(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn as_str_panics_if_not_contiguous() {
+        let _ = segmented(&[b"Hello", b" World"]).as_str();
+    }
+
+    #[test]
+    fn it_compares_to_strings() {
+        let bytes = Bytes::from_static(b"OK");
+        assert_eq!(bytes, "OK");
+        assert_eq!("OK", bytes);
+        assert_ne!(bytes, "ERR");
+        assert_ne!("ERR", bytes);
+        assert_eq!(bytes, *"OK");
+        assert_eq!(bytes, String::from("OK"));
+        assert_eq!(String::from("OK"), bytes);
+        assert_ne!(bytes, String::from("OK!"));
+        assert_ne!(Bytes::from_static(b"\xff"), "\u{ff}");
+    }
+
+    #[test]
+    fn it_formats_as_hex() {
+        let bytes = Bytes::from(&b"\x0a\xff"[..]);
+        assert_eq!(format!("{bytes:x}"), "0aff");
+        assert_eq!(format!("{bytes:X}"), "0AFF");
+        assert_eq!(format!("{bytes:#x}"), "0x0aff");
+        assert_eq!(format!("{bytes:#X}"), "0X0AFF");
+        assert_eq!(format!("{bytes:8x}"), "    0aff");
+        assert_eq!(format!("{bytes:*<8x}"), "0aff****");
+        assert_eq!(format!("{bytes:^9x}"), "  0aff   ");
+        assert_eq!(format!("{bytes:#010x}"), "0x00000aff");
+        assert_eq!(format!("{:x}", Bytes::new()), "");
+    }
+
+    #[test]
+    fn borrowed_views_are_copied() {
+        let mut bytes_mut = BytesMut::new();
+        bytes_mut.extend_from_slice(b"Hello World");
+        let bytes = Buf::view(&bytes_mut, 0..5).unwrap().to_bytes();
+        drop(bytes_mut);
+        assert_eq!(bytes, b"Hello");
+    }
+
+    #[test]
+    fn it_copies_from_arrays_and_vecs() {
+        let array = [1, 2, 3];
+        let bytes = Bytes::from(array);
+        assert_eq!(bytes, [1, 2, 3]);
+
+        let vec = vec![4, 5];
+        let ptr = vec.as_ptr();
+        let bytes = Bytes::from(vec);
+        assert_eq!(bytes, [4, 5]);
+        assert_eq!(bytes.contiguous().unwrap().as_ptr(), ptr);
+
+        let before = num_allocations();
+        let empty = Bytes::from([0u8; 0]);
+        let empty_vec = Bytes::from(Vec::new());
+        assert_eq!(num_allocations(), before);
+        assert!(empty.is_empty());
+        assert!(empty_vec.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_converts_std_cursor() {
+        let mut cursor = std::io::Cursor::new(b"Hello World".to_vec());
+        cursor.set_position(3);
+        let (bytes, position) = Bytes::from_std_cursor(cursor);
+        assert_eq!(bytes, b"Hello World");
+        assert_eq!(position, 3);
+        assert_eq!(bytes.view(3..).unwrap(), b"lo World");
+
+        let mut cursor = bytes.into_std_cursor(position);
+        assert_eq!(cursor.position(), 3);
+        let mut rest = String::new();
+        std::io::Read::read_to_string(&mut cursor, &mut rest).unwrap();
+        assert_eq!(rest, "lo World");
+        assert_eq!(cursor.into_inner(), b"Hello World");
+    }
+}
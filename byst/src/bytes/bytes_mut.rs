@@ -1,4 +1,7 @@
-use std::fmt::Debug;
+use alloc::boxed::Box;
+use core::fmt::Debug;
+#[cfg(feature = "std")]
+use std::io::IoSliceMut;
 
 use super::{
     r#impl::BytesMutImpl,
@@ -16,6 +19,8 @@ use crate::{
         Length,
         SizeLimit,
     },
+    copy_io,
+    io::BufWriter,
     util::{
         buf_eq,
         cfg_pub,
@@ -23,6 +28,8 @@ use crate::{
     },
     Buf,
     BufMut,
+    Bytes,
+    IndexOutOfBounds,
     Range,
     RangeOutOfBounds,
 };
@@ -34,7 +41,7 @@ pub struct BytesMut {
 impl BytesMut {
     cfg_pub! {
         #[inline]
-        pub(#[cfg(feature = "bytes-impl")]) fn from_impl(inner: Box<dyn BytesMutImpl>) -> Self {
+        pub(#[cfg(feature = "bytes-impl")]) fn from_impl(inner: Box<dyn BytesMutImpl<'static>>) -> Self {
             Self {
                 inner: ViewMut::from_impl(inner),
             }
@@ -50,6 +57,219 @@ impl BytesMut {
     pub fn with_capacity(capacity: usize) -> Self {
         Self::from_impl(Box::new(ArcBufMut::new(capacity)))
     }
+
+    /// Appends `with` to the end of the buffer.
+    ///
+    /// If the underlying buffer is full, a new buffer is allocated, and the
+    /// contents are copied into it. The new buffer has at least twice the
+    /// capacity, so that repeated appends take amortized linear time.
+    pub fn extend_from_slice(&mut self, with: &[u8]) {
+        let length = self.len();
+
+        let extended = {
+            let mut writer = self.writer();
+            writer.advance(length).is_ok() && writer.extend(with).is_ok()
+        };
+        if !extended {
+            self.grow(with.len());
+            let mut writer = self.writer();
+            writer
+                .advance(length)
+                .and_then(|()| writer.extend(with))
+                .expect("Newly allocated buffer is too small");
+        }
+    }
+
+    /// Makes sure that at least `additional` bytes can be appended without
+    /// allocating, by growing the buffer if necessary.
+    pub(crate) fn reserve_additional(&mut self, additional: usize) {
+        if self.reserve(self.len() + additional).is_err() {
+            self.grow(additional);
+        }
+    }
+
+    /// Moves the contents into a new buffer, that has room for at least
+    /// `additional` more bytes.
+    pub(crate) fn grow(&mut self, additional: usize) {
+        let length = self.len();
+        let capacity = match self.size_limit() {
+            SizeLimit::Exact(capacity) => capacity,
+            _ => length,
+        };
+
+        let mut grown = ArcBufMut::new(ArcBufMut::grown_capacity(capacity, length + additional));
+        let n_copied = copy_io(BufMut::writer(&mut grown), self.reader(), None);
+        assert_eq!(
+            n_copied, length,
+            "Expected to copy {length} bytes, but copied {n_copied}."
+        );
+        *self = grown.into();
+    }
+
+    /// Converts this into an immutable [`Bytes`].
+    ///
+    /// This doesn't copy. The [`Bytes`] shares the underlying buffer.
+    #[inline]
+    pub fn freeze(self) -> Bytes {
+        self.inner.freeze().into()
+    }
+
+    /// Splits off the filled bytes and returns them.
+    ///
+    /// `self` is empty afterwards, but keeps the remaining capacity of the
+    /// underlying buffer. Subsequent writes to `self` won't affect the
+    /// returned [`BytesMut`].
+    #[inline]
+    pub fn split(&mut self) -> BytesMut {
+        self.split_to(self.len())
+            .expect("Splitting at the buffer's length failed")
+    }
+
+    /// Splits off the first `at` bytes and returns them, leaving the rest in
+    /// `self`.
+    #[inline]
+    pub fn split_to(&mut self, at: usize) -> Result<BytesMut, IndexOutOfBounds> {
+        Ok(Self {
+            inner: self.inner.split_at(at)?,
+        })
+    }
+
+    /// Splits off the bytes from `at` on and returns them, leaving the first
+    /// `at` bytes in `self`.
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Result<BytesMut, IndexOutOfBounds> {
+        let left = self.split_to(at)?;
+        Ok(core::mem::replace(self, left))
+    }
+
+    /// Joins `other` back onto the end of `self`.
+    ///
+    /// This is the inverse of [`split_off`](Self::split_off): If `other` was
+    /// split off from `self` and both are still adjacent in the same buffer,
+    /// this doesn't copy. If they aren't adjacent, `other` is returned.
+    ///
+    /// If either has no capacity, this always succeeds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use byst::BytesMut;
+    /// let mut buf = BytesMut::with_capacity(16);
+    /// buf.extend_from_slice(b"Hello World");
+    ///
+    /// let tail = buf.split_off(5).unwrap();
+    /// assert_eq!(tail, b" World");
+    ///
+    /// buf.unsplit(tail).unwrap();
+    /// assert_eq!(buf, b"Hello World");
+    /// ```
+    #[inline]
+    pub fn unsplit(&mut self, other: BytesMut) -> Result<(), BytesMut> {
+        self.inner
+            .unsplit(other.inner)
+            .map_err(|inner| Self { inner })
+    }
+
+    /// Sets the length of the filled portion to `to`.
+    ///
+    /// Use this to include bytes that were read into the spare capacity
+    /// exposed by [`BufMut::io_slices_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer hasn't been initialized upto `to` (see
+    /// [`ArcBufMut::set_filled_to`]), or if the underlying buffer doesn't
+    /// support changing its length.
+    #[inline]
+    pub fn set_filled_to(&mut self, to: usize) {
+        self.inner.set_filled_to(to);
+    }
+
+    /// Returns the initialized spare capacity after the filled portion.
+    ///
+    /// This is empty, if the underlying buffer can't be written in place.
+    #[inline]
+    pub(crate) fn spare_capacity_mut(&mut self) -> &mut [u8] {
+        self.inner.spare_capacity_mut()
+    }
+
+    /// Removes the first `by` bytes from the buffer.
+    ///
+    /// This doesn't copy. The remaining bytes still share the underlying
+    /// buffer, and writes after them can still use its spare capacity.
+    #[inline]
+    pub fn advance(&mut self, by: usize) -> Result<(), IndexOutOfBounds> {
+        self.inner.split_at(by)?;
+        Ok(())
+    }
+}
+
+impl Extend<u8> for BytesMut {
+    #[inline]
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        let mut iter = iter.into_iter();
+        loop {
+            let length = self.len();
+
+            // write into the spare capacity until the buffer is full.
+            let full = {
+                let mut writer = self.writer();
+                writer
+                    .advance(length)
+                    .expect("Advancing the writer over the filled bytes failed");
+                iter.by_ref().find(|byte| writer.extend(&[*byte]).is_err())
+            };
+            let Some(byte) = full
+            else {
+                break;
+            };
+
+            self.grow(1 + iter.size_hint().0);
+            self.extend_from_slice(&[byte]);
+        }
+    }
+}
+
+impl<'a> Extend<&'a u8> for BytesMut {
+    #[inline]
+    fn extend<T: IntoIterator<Item = &'a u8>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl core::fmt::Write for BytesMut {
+    /// Appends the UTF-8 bytes of `s` to the buffer.
+    ///
+    /// This never fails, since the buffer grows as needed (see
+    /// [`BytesMut::extend_from_slice`]).
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl From<ViewMut<'static>> for BytesMut {
+    #[inline]
+    fn from(inner: ViewMut<'static>) -> Self {
+        Self { inner }
+    }
+}
+
+impl FromIterator<u8> for BytesMut {
+    /// Collects the bytes into a new [`BytesMut`].
+    ///
+    /// If the iterator is empty, this doesn't allocate.
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let buf = iter.into_iter().collect::<ArcBufMut>();
+        if buf.capacity() == 0 {
+            Self::new()
+        }
+        else {
+            buf.into()
+        }
+    }
 }
 
 impl Default for BytesMut {
@@ -61,7 +281,7 @@ impl Default for BytesMut {
 
 impl Debug for BytesMut {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         debug_as_hexdump(f, self)
     }
 }
@@ -128,4 +348,189 @@ impl BufMut for BytesMut {
     fn size_limit(&self) -> SizeLimit {
         self.inner.size_limit()
     }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn io_slices_mut<'a>(&'a mut self, dst: &mut [IoSliceMut<'a>]) -> usize {
+        self.inner.io_slices_mut(dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BytesMut;
+    use crate::{
+        buf::{
+            Length,
+            SizeLimit,
+        },
+        io::BufReader,
+        Buf,
+        BufMut,
+        Bytes,
+    };
+
+    #[test]
+    fn it_collects_from_iterator() {
+        let bytes_mut = (0..10u8).collect::<BytesMut>();
+        assert_eq!(bytes_mut, b"\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09");
+    }
+
+    #[test]
+    fn repeated_appends_grow_amortized() {
+        let capacity = |buf: &BytesMut| {
+            match buf.size_limit() {
+                SizeLimit::Exact(capacity) => capacity,
+                _ => 0,
+            }
+        };
+
+        let mut buf = BytesMut::new();
+        let mut num_grown = 0;
+        for i in 0..1000u32 {
+            let before = capacity(&buf);
+            buf.extend_from_slice(&[i as u8]);
+            if capacity(&buf) != before {
+                num_grown += 1;
+            }
+        }
+        assert!(num_grown <= 11);
+        assert_eq!(buf.len(), 1000);
+
+        let mut buf = BytesMut::with_capacity(4);
+        buf.extend((0..100u8).filter(|i| i % 2 == 0));
+        assert_eq!(buf, (0..100u8).step_by(2).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn unsplit_joins_adjacent_halves() {
+        let mut buf = BytesMut::with_capacity(16);
+        buf.extend_from_slice(b"Hello World");
+
+        let mut tail = buf.split_off(5).unwrap();
+        let world = tail.split_off(1).unwrap();
+        assert_eq!(buf, b"Hello");
+        assert_eq!(tail, b" ");
+        assert_eq!(world, b"World");
+
+        // not adjacent
+        let world = buf.unsplit(world).unwrap_err();
+        assert_eq!(buf, b"Hello");
+
+        buf.unsplit(tail).unwrap();
+        buf.unsplit(world).unwrap();
+        assert_eq!(buf, b"Hello World");
+
+        // the spare capacity is still usable.
+        buf.extend_from_slice(b"!");
+        assert_eq!(buf, b"Hello World!");
+    }
+
+    #[test]
+    fn unsplit_rejoins_empty_spare_capacity() {
+        let mut buf = BytesMut::with_capacity(16);
+        buf.extend_from_slice(b"Hello");
+
+        let spare = buf.split_off(5).unwrap();
+        assert!(spare.is_empty());
+        assert!(matches!(buf.size_limit(), SizeLimit::Exact(5)));
+
+        buf.unsplit(spare).unwrap();
+        assert_eq!(buf, b"Hello");
+        assert!(matches!(buf.size_limit(), SizeLimit::Exact(16)));
+    }
+
+    #[test]
+    fn unsplit_rejects_different_buffers() {
+        let mut a = BytesMut::with_capacity(8);
+        a.extend_from_slice(b"Hello");
+        let mut b = BytesMut::with_capacity(8);
+        b.extend_from_slice(b"World");
+
+        let b = a.unsplit(b).unwrap_err();
+        assert_eq!(a, b"Hello");
+        assert_eq!(b, b"World");
+
+        let mut empty = BytesMut::new();
+        empty.unsplit(b).unwrap();
+        assert_eq!(empty, b"World");
+    }
+
+    #[test]
+    fn it_compares_with_bytes() {
+        let bytes_mut = b"Hello".iter().copied().collect::<BytesMut>();
+        let bytes = Bytes::from(*b"Hello");
+        assert_eq!(bytes_mut, bytes);
+        assert_eq!(bytes, bytes_mut);
+        assert_eq!(&bytes_mut, &bytes);
+        assert_eq!(&bytes, &bytes_mut);
+
+        let shorter = Bytes::from_static(b"Hell");
+        assert_ne!(bytes_mut, shorter);
+        assert_ne!(shorter, bytes_mut);
+    }
+
+    #[test]
+    fn it_formats_into_buffer() {
+        use std::fmt::Write;
+
+        let mut bytes_mut = BytesMut::with_capacity(4);
+        write!(bytes_mut, "x={}", 42).unwrap();
+        write!(bytes_mut, ", y={}", 1337).unwrap();
+        assert_eq!(bytes_mut, b"x=42, y=1337");
+    }
+
+    #[test]
+    fn it_extends_empty_buffer() {
+        let mut bytes_mut = BytesMut::new();
+        bytes_mut.extend(b"Hello");
+        bytes_mut.extend(b" World".iter().copied());
+        assert_eq!(bytes_mut, b"Hello World");
+    }
+
+    #[test]
+    fn it_advances() {
+        let mut bytes_mut = BytesMut::with_capacity(16);
+        bytes_mut.extend_from_slice(b"Hello World");
+        bytes_mut.advance(6).unwrap();
+        assert_eq!(bytes_mut, b"World");
+        bytes_mut.extend_from_slice(b"!");
+        assert_eq!(bytes_mut, b"World!");
+        assert!(bytes_mut.advance(7).is_err());
+        bytes_mut.advance(6).unwrap();
+        assert!(bytes_mut.is_empty());
+    }
+
+    #[test]
+    fn frozen_split_isnt_affected_by_writes_to_tail() {
+        let mut bytes_mut = BytesMut::with_capacity(16);
+        bytes_mut.extend_from_slice(b"Hello");
+        let frozen = bytes_mut.split().freeze();
+        assert!(bytes_mut.is_empty());
+
+        bytes_mut.extend_from_slice(b"World");
+        assert_eq!(frozen, b"Hello");
+        assert_eq!(bytes_mut, b"World");
+        assert_eq!(
+            frozen.contiguous().unwrap().as_ptr_range().end,
+            bytes_mut.reader().peek_chunk().unwrap().as_ptr(),
+        );
+    }
+
+    #[test]
+    fn it_splits_to_and_off() {
+        let mut bytes_mut = BytesMut::with_capacity(16);
+        bytes_mut.extend_from_slice(b"Hello World");
+
+        let hello = bytes_mut.split_to(5).unwrap();
+        assert_eq!(hello, b"Hello");
+        assert_eq!(bytes_mut, b" World");
+
+        let world = bytes_mut.split_off(1).unwrap();
+        assert_eq!(bytes_mut, b" ");
+        assert_eq!(world, b"World");
+
+        assert!(bytes_mut.split_to(2).is_err());
+        assert!(bytes_mut.split_off(2).is_err());
+    }
 }
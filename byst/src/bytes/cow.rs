@@ -0,0 +1,272 @@
+use core::fmt::Debug;
+
+use super::Bytes;
+use crate::{
+    buf::Length,
+    impl_me,
+    io::{
+        BufReader,
+        End,
+        Seek,
+    },
+    util::{
+        buf_eq,
+        debug_as_hexdump,
+    },
+    Buf,
+    Range,
+    RangeOutOfBounds,
+};
+
+/// Bytes that are either borrowed or owned.
+///
+/// This is the byte-buffer analog of [`alloc::borrow::Cow`]. Parsers can use it
+/// to borrow from their input where possible, and only allocate where they
+/// must.
+#[derive(Clone)]
+pub enum CowBytes<'a> {
+    Borrowed(&'a [u8]),
+    Owned(Bytes),
+}
+
+impl<'a> CowBytes<'a> {
+    /// Returns `true` if the bytes are borrowed.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Returns `true` if the bytes are owned.
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Converts into owned [`Bytes`].
+    ///
+    /// This copies the bytes if they're borrowed. Owned bytes are moved out
+    /// without copying.
+    #[inline]
+    pub fn into_owned(self) -> Bytes {
+        match self {
+            Self::Borrowed(slice) => slice.to_bytes(),
+            Self::Owned(bytes) => bytes,
+        }
+    }
+}
+
+impl<'a> From<&'a [u8]> for CowBytes<'a> {
+    #[inline]
+    fn from(value: &'a [u8]) -> Self {
+        Self::Borrowed(value)
+    }
+}
+
+impl<'a> From<Bytes> for CowBytes<'a> {
+    #[inline]
+    fn from(value: Bytes) -> Self {
+        Self::Owned(value)
+    }
+}
+
+impl<'a> Default for CowBytes<'a> {
+    #[inline]
+    fn default() -> Self {
+        Self::Borrowed(&[])
+    }
+}
+
+impl<'a> Debug for CowBytes<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        debug_as_hexdump(f, self)
+    }
+}
+
+impl<'a, R: Buf> PartialEq<R> for CowBytes<'a> {
+    #[inline]
+    fn eq(&self, other: &R) -> bool {
+        buf_eq(self, other)
+    }
+}
+
+impl<'a> Length for CowBytes<'a> {
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Self::Borrowed(slice) => slice.len(),
+            Self::Owned(bytes) => bytes.len(),
+        }
+    }
+}
+
+impl<'a> Buf for CowBytes<'a> {
+    type View<'b> = CowBytes<'b>
+    where
+        Self: 'b;
+
+    type Reader<'b> = CowBytes<'b>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn view(&self, range: impl Into<Range>) -> Result<Self::View<'_>, RangeOutOfBounds> {
+        match self {
+            Self::Borrowed(slice) => Ok(CowBytes::Borrowed(Buf::view(slice, range)?)),
+            Self::Owned(bytes) => Ok(CowBytes::Owned(Buf::view(bytes, range)?)),
+        }
+    }
+
+    #[inline]
+    fn reader(&self) -> Self::Reader<'_> {
+        match self {
+            Self::Borrowed(slice) => CowBytes::Borrowed(slice),
+            Self::Owned(bytes) => CowBytes::Owned(bytes.clone()),
+        }
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        match self {
+            Self::Borrowed(_) => true,
+            Self::Owned(bytes) => bytes.is_contiguous(),
+        }
+    }
+
+    #[inline]
+    fn to_bytes(&self) -> Bytes {
+        match self {
+            Self::Borrowed(slice) => slice.to_bytes(),
+            Self::Owned(bytes) => bytes.clone(),
+        }
+    }
+}
+
+impl<'a> BufReader for CowBytes<'a> {
+    type View = Self;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        match self {
+            Self::Borrowed(slice) => slice.peek_chunk(),
+            Self::Owned(bytes) => bytes.peek_chunk(),
+        }
+    }
+
+    #[inline]
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        match self {
+            Self::Borrowed(slice) => Ok(Self::Borrowed(BufReader::view(slice, length)?)),
+            Self::Owned(bytes) => Ok(Self::Owned(BufReader::view(bytes, length)?)),
+        }
+    }
+
+    #[inline]
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        match self {
+            Self::Borrowed(slice) => Ok(Self::Borrowed(slice.peek_view(length)?)),
+            Self::Owned(bytes) => Ok(Self::Owned(bytes.peek_view(length)?)),
+        }
+    }
+
+    #[inline]
+    fn rest(&mut self) -> Self::View {
+        match self {
+            Self::Borrowed(slice) => Self::Borrowed(slice.rest()),
+            Self::Owned(bytes) => Self::Owned(bytes.rest()),
+        }
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        self.clone()
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        match self {
+            Self::Borrowed(slice) => BufReader::advance(slice, by),
+            Self::Owned(bytes) => BufReader::advance(bytes, by),
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a> Seek for CowBytes<'a> {
+    type Position = Self;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        self.clone()
+    }
+
+    #[inline]
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        core::mem::replace(self, position.clone())
+    }
+}
+
+impl_me! {
+    impl['a] Reader for CowBytes<'a> as BufReader;
+    impl['a] Read<_, ()> for CowBytes<'a> as BufReader::View;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CowBytes;
+    use crate::{
+        buf::{
+            arc_buf::ArcBufMut,
+            Buf,
+            Length,
+        },
+        io::BufReader,
+        util::counting_allocator::num_allocations,
+        Bytes,
+    };
+
+    #[test]
+    fn into_owned_copies_borrowed() {
+        let data = b"Hello World".to_vec();
+        let cow = CowBytes::from(&data[..]);
+        assert!(cow.is_borrowed());
+
+        let before = num_allocations();
+        let bytes = cow.into_owned();
+        assert!(num_allocations() > before);
+        drop(data);
+        assert_eq!(bytes, b"Hello World");
+    }
+
+    #[test]
+    fn into_owned_moves_owned() {
+        let buf: ArcBufMut = b"Hello World".iter().copied().collect();
+        let buf = buf.freeze();
+        let cow = CowBytes::from(Bytes::from(buf.clone()));
+        assert!(cow.is_owned());
+        assert_eq!(buf.ref_count().ref_count(), Some(2));
+
+        let before = num_allocations();
+        let bytes = cow.into_owned();
+        assert_eq!(num_allocations(), before);
+        assert_eq!(buf.ref_count().ref_count(), Some(2));
+        assert_eq!(bytes, b"Hello World");
+    }
+
+    #[test]
+    fn it_reads_both_variants() {
+        for mut cow in [
+            CowBytes::Borrowed(b"Hello World"),
+            CowBytes::Owned(Bytes::from_static(b"Hello World")),
+        ] {
+            assert_eq!(cow.len(), 11);
+            assert_eq!(Buf::view(&cow, 6..).unwrap(), b"World");
+            assert_eq!(BufReader::view(&mut cow, 5).unwrap(), b"Hello");
+            assert_eq!(cow.rest(), b" World");
+        }
+    }
+}
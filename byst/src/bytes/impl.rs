@@ -1,7 +1,15 @@
 #![allow(dead_code)]
 
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::io::IoSliceMut;
+
 use crate::{
     buf::{
+        arc_buf::{
+            ArcBufMut,
+            RefCount,
+        },
         Full,
         Length,
         SizeLimit,
@@ -26,6 +34,27 @@ pub trait BytesImpl<'b>: Length + Send + Sync {
     fn peek_chunk(&self) -> Option<&[u8]>;
     fn view(&self, range: Range) -> Result<Box<dyn BytesImpl<'b> + 'b>, RangeOutOfBounds>;
     fn advance(&mut self, by: usize) -> Result<(), End>;
+
+    /// Converts into a [`BytesMutImpl`] without copying, if this is the only
+    /// reference to the underlying buffer. Otherwise `self` is returned.
+    fn try_into_mut(
+        self: Box<Self>,
+    ) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, Box<dyn BytesImpl<'b> + 'b>>;
+
+    /// Returns the reference count of the underlying buffer.
+    ///
+    /// The default implementation returns [`RefCount::Static`], for buffers
+    /// that aren't reference-counted.
+    fn ref_count(&self) -> RefCount {
+        RefCount::Static
+    }
+
+    /// Returns `true` if [`try_into_mut`](Self::try_into_mut) would succeed.
+    ///
+    /// The default implementation returns `false`.
+    fn is_unique(&self) -> bool {
+        false
+    }
 }
 
 /// The trait backing the [`BytesMut`] implementation.
@@ -36,17 +65,54 @@ pub trait BytesImpl<'b>: Length + Send + Sync {
 ///
 /// [`BytesMut`]: super::BytesMut
 /// [`BytesMut::from_impl`]: super::BytesMut::from_impl
-pub trait BytesMutImpl: Length + Send + Sync {
+pub trait BytesMutImpl<'b>: Length + Send + Sync {
     fn view(&self, range: Range) -> Result<Box<dyn BytesImpl<'_> + '_>, RangeOutOfBounds>;
-    fn view_mut(&mut self, range: Range) -> Result<Box<dyn BytesMutImpl + '_>, RangeOutOfBounds>;
+    fn view_mut(
+        &mut self,
+        range: Range,
+    ) -> Result<Box<dyn BytesMutImpl<'_> + '_>, RangeOutOfBounds>;
     fn reader(&self) -> Box<dyn BytesImpl<'_> + '_>;
     fn writer(&mut self) -> Box<dyn WriterImpl + '_>;
     fn reserve(&mut self, size: usize) -> Result<(), Full>;
     fn size_limit(&self) -> SizeLimit;
-    fn split_at(
-        &mut self,
-        at: usize,
-    ) -> Result<Box<dyn BytesMutImpl + '_>, IndexOutOfBounds>;
+    fn split_at(&mut self, at: usize) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, IndexOutOfBounds>;
+    fn freeze(self: Box<Self>) -> Box<dyn BytesImpl<'b> + 'b>;
+
+    /// Converts into an [`ArcBufMut`] without copying, if this is one, or an
+    /// empty buffer. Otherwise `self` is returned.
+    fn into_arc_buf_mut(self: Box<Self>) -> Result<ArcBufMut, Box<dyn BytesMutImpl<'b> + 'b>>;
+
+    /// Exposes the spare capacity for vectored reads. See
+    /// [`BufMut::io_slices_mut`].
+    ///
+    /// The default implementation doesn't expose any spare capacity and
+    /// returns 0.
+    #[cfg(feature = "std")]
+    fn io_slices_mut<'a>(&'a mut self, _dst: &mut [IoSliceMut<'a>]) -> usize {
+        0
+    }
+
+    /// Sets the length of the filled portion to `to`. See
+    /// [`ArcBufMut::set_filled_to`].
+    ///
+    /// The default implementation only accepts the current length, and panics
+    /// otherwise.
+    fn set_filled_to(&mut self, to: usize) {
+        assert_eq!(
+            to,
+            self.len(),
+            "`BytesMutImpl::set_filled_to`: Buffer doesn't support changing its length"
+        );
+    }
+
+    /// Returns the initialized spare capacity after the filled portion, so
+    /// that it can be written in place. Written bytes are included with
+    /// [`set_filled_to`](Self::set_filled_to).
+    ///
+    /// The default implementation doesn't expose any spare capacity.
+    fn spare_capacity_mut(&mut self) -> &mut [u8] {
+        &mut []
+    }
 }
 
 pub trait WriterImpl {
@@ -72,14 +138,23 @@ impl<'b> BytesImpl<'b> for &'b [u8] {
     fn advance(&mut self, by: usize) -> Result<(), End> {
         BufReader::advance(self, by)
     }
+
+    fn try_into_mut(
+        self: Box<Self>,
+    ) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, Box<dyn BytesImpl<'b> + 'b>> {
+        Err(self)
+    }
 }
 
-impl<'b> BytesMutImpl for &'b mut [u8] {
+impl<'b> BytesMutImpl<'b> for &'b mut [u8] {
     fn view(&self, range: Range) -> Result<Box<dyn BytesImpl + '_>, RangeOutOfBounds> {
         Ok(Box::new(Buf::view(self, range)?))
     }
 
-    fn view_mut(&mut self, range: Range) -> Result<Box<dyn BytesMutImpl + '_>, RangeOutOfBounds> {
+    fn view_mut(
+        &mut self,
+        range: Range,
+    ) -> Result<Box<dyn BytesMutImpl<'_> + '_>, RangeOutOfBounds> {
         Ok(Box::new(BufMut::view_mut(self, range)?))
     }
 
@@ -99,14 +174,26 @@ impl<'b> BytesMutImpl for &'b mut [u8] {
         BufMut::size_limit(self)
     }
 
-    fn split_at(
-        &mut self,
-        at: usize,
-    ) -> Result<Box<dyn BytesMutImpl + '_>, IndexOutOfBounds> {
-        let (left, right) = <[u8]>::split_at_mut(std::mem::take(self), at);
+    fn split_at(&mut self, at: usize) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, IndexOutOfBounds> {
+        if at > <[u8]>::len(self) {
+            return Err(IndexOutOfBounds {
+                required: at,
+                bounds: (0, <[u8]>::len(self)),
+            });
+        }
+        let (left, right) = <[u8]>::split_at_mut(core::mem::take(self), at);
         *self = right;
         Ok(Box::new(left))
     }
+
+    fn freeze(self: Box<Self>) -> Box<dyn BytesImpl<'b> + 'b> {
+        let this: &'b [u8] = *self;
+        Box::new(this)
+    }
+
+    fn into_arc_buf_mut(self: Box<Self>) -> Result<ArcBufMut, Box<dyn BytesMutImpl<'b> + 'b>> {
+        Err(self)
+    }
 }
 
 impl<'b> WriterImpl for &'b mut [u8] {
@@ -1,8 +1,9 @@
 #[allow(clippy::module_inception)]
 pub mod bytes;
 pub mod bytes_mut;
+pub mod cow;
+pub mod small_bytes;
 //mod spilled;
-mod r#static;
 pub mod view;
 
 cfg_pub! {
@@ -12,5 +13,7 @@ cfg_pub! {
 pub use self::{
     bytes::Bytes,
     bytes_mut::BytesMut,
+    cow::CowBytes,
+    small_bytes::SmallBytes,
 };
 use crate::util::cfg_pub;
@@ -0,0 +1,367 @@
+use alloc::boxed::Box;
+use core::fmt::Debug;
+
+use super::r#impl::{
+    BytesImpl,
+    BytesMutImpl,
+};
+use crate::{
+    buf::{
+        arc_buf::{
+            ArcBuf,
+            ArcBufMut,
+            RefCount,
+        },
+        Length,
+    },
+    copy,
+    impl_me,
+    io::{
+        BufReader,
+        End,
+        Seek,
+    },
+    util::{
+        buf_eq,
+        debug_as_hexdump,
+    },
+    Buf,
+    Bytes,
+    Range,
+    RangeOutOfBounds,
+};
+
+/// Bytes that are stored inline, if they fit into
+/// [`SmallBytes::INLINE_CAPACITY`] bytes. Larger buffers are stored in an
+/// [`ArcBuf`].
+///
+/// Inline buffers don't allocate, and clones of them are cheap copies.
+#[derive(Clone)]
+pub struct SmallBytes {
+    inner: Inner,
+}
+
+// note: `ArcBuf` contains a `bool`, so the discriminant fits into its niche and
+// this enum is no larger than the `ArcBuf`.
+#[derive(Clone)]
+enum Inner {
+    Inline {
+        buf: [u8; SmallBytes::INLINE_CAPACITY],
+        start: u8,
+        end: u8,
+    },
+    Heap(ArcBuf),
+}
+
+impl SmallBytes {
+    /// Maximum number of bytes that are stored inline.
+    pub const INLINE_CAPACITY: usize = 22;
+
+    /// Creates an empty [`SmallBytes`].
+    ///
+    /// This doesn't allocate.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: Inner::Inline {
+                buf: [0; Self::INLINE_CAPACITY],
+                start: 0,
+                end: 0,
+            },
+        }
+    }
+
+    /// Returns whether the bytes are stored inline.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.inner, Inner::Inline { .. })
+    }
+
+    /// Returns the reference count of the underlying buffer.
+    ///
+    /// This is [`RefCount::Static`] for inline buffers.
+    #[inline]
+    pub fn ref_count(&self) -> RefCount {
+        match &self.inner {
+            Inner::Inline { .. } => RefCount::Static,
+            Inner::Heap(buf) => buf.ref_count(),
+        }
+    }
+
+    #[inline]
+    fn bytes(&self) -> &[u8] {
+        match &self.inner {
+            Inner::Inline { buf, start, end } => &buf[usize::from(*start)..usize::from(*end)],
+            Inner::Heap(buf) => buf.as_ref(),
+        }
+    }
+
+    fn end(&self, requested: usize) -> End {
+        End {
+            requested,
+            read: 0,
+            remaining: self.len(),
+        }
+    }
+}
+
+impl Default for SmallBytes {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> From<&'a [u8]> for SmallBytes {
+    fn from(value: &'a [u8]) -> Self {
+        let inner = if value.len() <= Self::INLINE_CAPACITY {
+            let mut buf = [0; Self::INLINE_CAPACITY];
+            buf[..value.len()].copy_from_slice(value);
+            Inner::Inline {
+                buf,
+                start: 0,
+                // this fits, since `INLINE_CAPACITY < 256`
+                end: value.len() as u8,
+            }
+        }
+        else {
+            let mut buf = ArcBufMut::new(value.len());
+            copy(&mut buf, value).expect("Newly allocated buffer is too small");
+            Inner::Heap(buf.freeze())
+        };
+        Self { inner }
+    }
+}
+
+impl From<ArcBuf> for SmallBytes {
+    #[inline]
+    fn from(value: ArcBuf) -> Self {
+        Self {
+            inner: Inner::Heap(value),
+        }
+    }
+}
+
+impl From<SmallBytes> for Bytes {
+    #[inline]
+    fn from(value: SmallBytes) -> Self {
+        match value.inner {
+            Inner::Heap(buf) => buf.into(),
+            Inner::Inline { .. } => Bytes::from_impl(Box::new(value)),
+        }
+    }
+}
+
+impl Length for SmallBytes {
+    #[inline]
+    fn len(&self) -> usize {
+        match &self.inner {
+            Inner::Inline { start, end, .. } => usize::from(end - start),
+            Inner::Heap(buf) => buf.len(),
+        }
+    }
+}
+
+impl Buf for SmallBytes {
+    type View<'a> = Self
+    where
+        Self: 'a;
+
+    type Reader<'a> = Self
+    where
+        Self: 'a;
+
+    fn view(&self, range: impl Into<Range>) -> Result<Self::View<'_>, RangeOutOfBounds> {
+        let inner = match &self.inner {
+            Inner::Inline { buf, start, end } => {
+                let (new_start, new_end) = range
+                    .into()
+                    .indices_checked_in(usize::from(*start), usize::from(*end))?;
+                Inner::Inline {
+                    buf: *buf,
+                    start: new_start as u8,
+                    end: new_end as u8,
+                }
+            }
+            Inner::Heap(buf) => Inner::Heap(Buf::view(buf, range)?),
+        };
+        Ok(Self { inner })
+    }
+
+    #[inline]
+    fn reader(&self) -> Self::Reader<'_> {
+        Clone::clone(self)
+    }
+}
+
+impl BufReader for SmallBytes {
+    type View = Self;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        if self.is_empty() {
+            None
+        }
+        else {
+            Some(self.bytes())
+        }
+    }
+
+    #[inline]
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        let view = self.peek_view(length)?;
+        BufReader::advance(self, length)?;
+        Ok(view)
+    }
+
+    #[inline]
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        Buf::view(self, ..length).map_err(|RangeOutOfBounds { .. }| self.end(length))
+    }
+
+    #[inline]
+    fn rest(&mut self) -> Self::View {
+        core::mem::take(self)
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        Clone::clone(self)
+    }
+
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        if by <= self.len() {
+            match &mut self.inner {
+                // this fits, since `by <= end - start`
+                Inner::Inline { start, .. } => *start += by as u8,
+                Inner::Heap(buf) => BufReader::advance(buf, by)?,
+            }
+            Ok(())
+        }
+        else {
+            Err(self.end(by))
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Seek for SmallBytes {
+    type Position = Self;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        Clone::clone(self)
+    }
+
+    #[inline]
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        core::mem::replace(self, Clone::clone(position))
+    }
+}
+
+impl<'b> BytesImpl<'b> for SmallBytes {
+    fn view(&self, range: Range) -> Result<Box<dyn BytesImpl<'b> + 'b>, RangeOutOfBounds> {
+        Ok(Box::new(Buf::view(self, range)?))
+    }
+
+    fn clone(&self) -> Box<dyn BytesImpl<'b> + 'b> {
+        Box::new(Clone::clone(self))
+    }
+
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        BufReader::peek_chunk(self)
+    }
+
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        BufReader::advance(self, by)
+    }
+
+    fn try_into_mut(
+        self: Box<Self>,
+    ) -> Result<Box<dyn BytesMutImpl<'b> + 'b>, Box<dyn BytesImpl<'b> + 'b>> {
+        Err(self)
+    }
+}
+
+impl AsRef<[u8]> for SmallBytes {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.bytes()
+    }
+}
+
+impl Debug for SmallBytes {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        debug_as_hexdump(f, self.bytes())
+    }
+}
+
+impl<T: Buf> PartialEq<T> for SmallBytes {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        buf_eq(self, other)
+    }
+}
+
+impl_me! {
+    impl Reader for SmallBytes as BufReader;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SmallBytes;
+    use crate::{
+        buf::{
+            arc_buf::ArcBuf,
+            Length,
+        },
+        io::BufReader,
+        util::counting_allocator::num_allocations,
+        Buf,
+        Bytes,
+    };
+
+    #[test]
+    fn small_buffers_dont_allocate() {
+        let before = num_allocations();
+        let mut bytes = SmallBytes::from(b"\x12\x34\x56\x78" as &[u8]);
+        let view = BufReader::view(&mut bytes, 2).unwrap();
+        let cloned = bytes.clone();
+        assert_eq!(num_allocations(), before);
+
+        assert!(bytes.is_inline());
+        assert!(bytes.ref_count().is_static());
+        assert_eq!(view, b"\x12\x34");
+        assert_eq!(cloned, b"\x56\x78");
+    }
+
+    #[test]
+    fn large_buffers_spill_to_heap() {
+        let bytes = SmallBytes::from(&[0xabu8; 100] as &[u8]);
+        assert!(!bytes.is_inline());
+        assert_eq!(bytes.ref_count().ref_count(), Some(1));
+        assert_eq!(Buf::view(&bytes, 98..).unwrap(), b"\xab\xab");
+    }
+
+    #[test]
+    fn it_converts_into_bytes() {
+        let bytes = Bytes::from(SmallBytes::from(b"Hello" as &[u8]));
+        assert_eq!(bytes, b"Hello");
+
+        let bytes = Bytes::from(SmallBytes::from(ArcBuf::default()));
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn it_is_no_larger_than_arc_buf() {
+        assert_eq!(
+            std::mem::size_of::<SmallBytes>(),
+            std::mem::size_of::<ArcBuf>()
+        );
+    }
+}
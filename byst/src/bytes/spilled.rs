@@ -1,6 +1,6 @@
 #![allow(unused_variables, dead_code)]
 
-use std::sync::Arc;
+use alloc::sync::Arc;
 
 use super::r#impl::BytesMutImpl;
 use crate::buf::{
@@ -1,4 +1,7 @@
-use std::fmt::Debug;
+use alloc::boxed::Box;
+use core::fmt::Debug;
+#[cfg(feature = "std")]
+use std::io::IoSliceMut;
 
 use super::r#impl::{
     BytesImpl,
@@ -7,6 +10,7 @@ use super::r#impl::{
 };
 use crate::{
     buf::{
+        arc_buf::RefCount,
         Empty,
         Full,
         Length,
@@ -26,39 +30,94 @@ use crate::{
     },
     Buf,
     BufMut,
+    IndexOutOfBounds,
     Range,
     RangeOutOfBounds,
 };
 
 pub struct View<'b> {
-    inner: Box<dyn BytesImpl<'b> + 'b>,
+    inner: Inner<'b>,
+}
+
+/// Slices are stored directly, so that views of them can be created without
+/// allocating, and in `const` contexts.
+enum Inner<'b> {
+    Slice(&'b [u8]),
+    Impl(Box<dyn BytesImpl<'b> + 'b>),
 }
 
 impl<'b> View<'b> {
     cfg_pub! {
         #[inline]
         pub(#[cfg(feature = "bytes-impl")]) fn from_impl(inner: Box<dyn BytesImpl<'b> + 'b>) -> Self {
-            Self { inner }
+            Self { inner: Inner::Impl(inner) }
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_slice(slice: &'b [u8]) -> Self {
+        Self {
+            inner: Inner::Slice(slice),
+        }
+    }
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        match &self.inner {
+            Inner::Slice(slice) => BufReader::peek_chunk(slice),
+            Inner::Impl(inner) => inner.peek_chunk(),
+        }
+    }
+
+    pub(crate) fn try_into_mut(self) -> Result<ViewMut<'b>, Self> {
+        match self.inner {
+            Inner::Slice(_) => Err(self),
+            Inner::Impl(inner) => {
+                inner
+                    .try_into_mut()
+                    .map(ViewMut::from_impl)
+                    .map_err(Self::from_impl)
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn ref_count(&self) -> RefCount {
+        match &self.inner {
+            Inner::Slice(_) => RefCount::Static,
+            Inner::Impl(inner) => inner.ref_count(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_unique(&self) -> bool {
+        match &self.inner {
+            Inner::Slice(_) => false,
+            Inner::Impl(inner) => inner.is_unique(),
         }
     }
 }
 
 impl<'b> Default for View<'b> {
+    #[inline]
     fn default() -> Self {
-        Self::from_impl(Box::new(Empty))
+        Self::from_slice(&[])
     }
 }
 
 impl<'b> Clone for View<'b> {
     #[inline]
     fn clone(&self) -> Self {
-        Self::from_impl(self.inner.clone())
+        match &self.inner {
+            Inner::Slice(slice) => Self::from_slice(slice),
+            Inner::Impl(inner) => Self::from_impl(BytesImpl::clone(&**inner)),
+        }
     }
 }
 
 impl<'b> Debug for View<'b> {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         debug_as_hexdump(f, self)
     }
 }
@@ -73,7 +132,10 @@ impl<'b, R: Buf> PartialEq<R> for View<'b> {
 impl<'b> Length for View<'b> {
     #[inline]
     fn len(&self) -> usize {
-        self.inner.len()
+        match &self.inner {
+            Inner::Slice(slice) => slice.len(),
+            Inner::Impl(inner) => inner.len(),
+        }
     }
 }
 
@@ -88,13 +150,22 @@ impl<'b> Buf for View<'b> {
 
     #[inline]
     fn view(&self, range: impl Into<Range>) -> Result<Self::View<'_>, RangeOutOfBounds> {
-        Ok(View::from_impl(self.inner.view(range.into())?))
+        match &self.inner {
+            Inner::Slice(slice) => Ok(View::from_slice(range.into().slice_get(slice)?)),
+            Inner::Impl(inner) => Ok(View::from_impl(inner.view(range.into())?)),
+        }
     }
 
     #[inline]
     fn reader(&self) -> Self::Reader<'_> {
         self.clone()
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.peek_chunk()
+            .map_or(self.is_empty(), |chunk| chunk.len() == self.len())
+    }
 }
 
 impl<'b> BufReader for View<'b> {
@@ -102,15 +173,13 @@ impl<'b> BufReader for View<'b> {
 
     #[inline]
     fn peek_chunk(&self) -> Option<&[u8]> {
-        self.inner.peek_chunk()
+        View::peek_chunk(self)
     }
 
     #[inline]
     fn view(&mut self, length: usize) -> Result<Self::View, End> {
         let view = self.peek_view(length)?;
-        self.inner
-            .advance(length)
-            .unwrap_or_else(|_| unreachable!());
+        BufReader::advance(self, length).unwrap_or_else(|_| unreachable!());
         Ok(view)
     }
 
@@ -120,14 +189,14 @@ impl<'b> BufReader for View<'b> {
             End {
                 read: 0,
                 requested: length,
-                remaining: self.inner.len(),
+                remaining: self.len(),
             }
         })
     }
 
     #[inline]
     fn rest(&mut self) -> Self::View {
-        std::mem::take(self)
+        core::mem::take(self)
     }
 
     #[inline]
@@ -137,12 +206,15 @@ impl<'b> BufReader for View<'b> {
 
     #[inline]
     fn advance(&mut self, by: usize) -> Result<(), End> {
-        self.inner.advance(by)
+        match &mut self.inner {
+            Inner::Slice(slice) => BufReader::advance(slice, by),
+            Inner::Impl(inner) => inner.advance(by),
+        }
     }
 
     #[inline]
     fn remaining(&self) -> usize {
-        self.inner.len()
+        self.len()
     }
 }
 
@@ -156,26 +228,72 @@ impl<'b> Seek for View<'b> {
 
     #[inline]
     fn seek(&mut self, position: &Self::Position) -> Self::Position {
-        std::mem::replace(self, position.clone())
+        core::mem::replace(self, position.clone())
     }
 }
 
 pub struct ViewMut<'b> {
-    inner: Box<dyn BytesMutImpl + 'b>,
+    inner: Box<dyn BytesMutImpl<'b> + 'b>,
 }
 
 impl<'b> ViewMut<'b> {
     cfg_pub! {
         #[inline]
-        pub(#[cfg(feature = "bytes-impl")]) fn from_impl(inner: Box<dyn BytesMutImpl + 'b>) -> Self {
+        pub(#[cfg(feature = "bytes-impl")]) fn from_impl(inner: Box<dyn BytesMutImpl<'b> + 'b>) -> Self {
             Self { inner }
         }
     }
+
+    /// Splits off the first `at` bytes, leaving the rest in `self`.
+    #[inline]
+    pub(crate) fn split_at(&mut self, at: usize) -> Result<ViewMut<'b>, IndexOutOfBounds> {
+        Ok(ViewMut::from_impl(self.inner.split_at(at)?))
+    }
+
+    #[inline]
+    pub(crate) fn freeze(self) -> View<'b> {
+        View::from_impl(self.inner.freeze())
+    }
+
+    /// Joins `other` back onto the end of `self`. See [`BytesMut::unsplit`].
+    ///
+    /// [`BytesMut::unsplit`]: super::BytesMut::unsplit
+    pub(crate) fn unsplit(&mut self, other: ViewMut<'b>) -> Result<(), ViewMut<'b>> {
+        let other = other.inner.into_arc_buf_mut().map_err(ViewMut::from_impl)?;
+        let this = core::mem::replace(&mut self.inner, Box::new(Empty));
+        let mut this = match this.into_arc_buf_mut() {
+            Ok(this) => this,
+            Err(this) => {
+                self.inner = this;
+                return Err(ViewMut::from_impl(Box::new(other)));
+            }
+        };
+        let result = this
+            .try_unsplit(other)
+            .map_err(|other| ViewMut::from_impl(Box::new(other)));
+        self.inner = Box::new(this);
+        result
+    }
+
+    /// Sets the length of the filled portion. See
+    /// [`BytesMut::set_filled_to`].
+    ///
+    /// [`BytesMut::set_filled_to`]: super::BytesMut::set_filled_to
+    #[inline]
+    pub(crate) fn set_filled_to(&mut self, to: usize) {
+        self.inner.set_filled_to(to);
+    }
+
+    /// Returns the initialized spare capacity after the filled portion.
+    #[inline]
+    pub(crate) fn spare_capacity_mut(&mut self) -> &mut [u8] {
+        self.inner.spare_capacity_mut()
+    }
 }
 
 impl<'b> Debug for ViewMut<'b> {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         debug_as_hexdump(f, self)
     }
 }
@@ -242,6 +360,12 @@ impl<'b> BufMut for ViewMut<'b> {
     fn size_limit(&self) -> SizeLimit {
         self.inner.size_limit()
     }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn io_slices_mut<'a>(&'a mut self, dst: &mut [IoSliceMut<'a>]) -> usize {
+        self.inner.io_slices_mut(dst)
+    }
 }
 
 pub struct ViewMutWriter<'b> {
@@ -0,0 +1,150 @@
+//! Implementations of the [`bytes`](::bytes) crate's traits.
+//!
+//! This is enabled with the `bytes-compat` feature, and lets [`Bytes`],
+//! [`ArcBufMut`] and [`BytesMut`] be used with libraries that use the
+//! [`bytes`](::bytes) crate.
+
+use ::bytes::buf::UninitSlice;
+
+use crate::{
+    buf::{
+        arc_buf::ArcBufMut,
+        Length,
+    },
+    io::BufReader,
+    Bytes,
+    BytesMut,
+};
+
+impl ::bytes::Buf for Bytes {
+    #[inline]
+    fn remaining(&self) -> usize {
+        BufReader::remaining(self)
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self.peek_chunk().unwrap_or_default()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `cnt > self.remaining()`, as required by `bytes::Buf`.
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        BufReader::advance(self, cnt).expect("Advanced past the end of Bytes");
+    }
+}
+
+unsafe impl ::bytes::BufMut for ArcBufMut {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `cnt > self.remaining_mut()`, as required by
+    /// `bytes::BufMut`.
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let filled = self.len() + cnt;
+        assert!(
+            filled <= self.capacity(),
+            "Advanced past the end of ArcBufMut"
+        );
+        // SAFETY: The caller guarantees that the `cnt` bytes after the filled portion
+        // have been initialized.
+        self.set_initialized_to(filled);
+        self.set_filled_to(filled);
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        let filled = self.len();
+        let spare = unsafe {
+            // SAFETY: `UninitSlice` only allows writing initialized bytes.
+            &mut self.uninitialized_mut()[filled..]
+        };
+        UninitSlice::uninit(spare)
+    }
+}
+
+/// Like the [`bytes`](::bytes) crate's `BytesMut`, this grows when it's full,
+/// so [`remaining_mut`](::bytes::BufMut::remaining_mut) is practically
+/// unlimited.
+unsafe impl ::bytes::BufMut for BytesMut {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        isize::MAX as usize - self.len()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `cnt` is larger than the chunk returned by
+    /// [`chunk_mut`](::bytes::BufMut::chunk_mut).
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.spare_capacity_mut().len(),
+            "Advanced past the end of BytesMut"
+        );
+        self.set_filled_to(self.len() + cnt);
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.spare_capacity_mut().is_empty() {
+            self.grow(64);
+        }
+        UninitSlice::new(self.spare_capacity_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        buf::arc_buf::ArcBufMut,
+        Bytes,
+        BytesMut,
+    };
+
+    fn read_all(mut buf: impl ::bytes::Buf) -> Vec<u8> {
+        let mut data = vec![0; buf.remaining()];
+        buf.copy_to_slice(&mut data);
+        data
+    }
+
+    #[test]
+    fn bytes_implements_buf() {
+        let bytes = Bytes::from_static(b"Hello World");
+        assert_eq!(read_all(bytes.clone()), b"Hello World");
+
+        let mut bytes = bytes;
+        ::bytes::Buf::advance(&mut bytes, 6);
+        assert_eq!(::bytes::Buf::get_u8(&mut bytes), b'W');
+        assert_eq!(bytes, b"orld");
+    }
+
+    #[test]
+    fn arc_buf_mut_implements_buf_mut() {
+        let mut buf = ArcBufMut::new(8);
+        ::bytes::BufMut::put_slice(&mut buf, b"Hello");
+        ::bytes::BufMut::put_u16(&mut buf, 0x2021);
+        assert_eq!(::bytes::BufMut::remaining_mut(&buf), 1);
+        assert_eq!(buf, b"Hello !");
+    }
+
+    #[test]
+    fn bytes_mut_implements_buf_mut() {
+        let mut buf = BytesMut::with_capacity(8);
+        ::bytes::BufMut::put_slice(&mut buf, b"Hello");
+        ::bytes::BufMut::put_slice(&mut buf, b" World");
+        ::bytes::BufMut::put_u8(&mut buf, b'!');
+        assert_eq!(buf, b"Hello World!");
+
+        let mut buf = BytesMut::new();
+        ::bytes::BufMut::put_bytes(&mut buf, b'a', 100);
+        assert_eq!(buf, [b'a'; 100]);
+    }
+}
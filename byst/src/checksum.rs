@@ -0,0 +1,262 @@
+//! Incremental checksums over buffers.
+//!
+//! The checksums are fed chunk by chunk, so buffers don't need to be
+//! contiguous.
+
+use crate::{
+    util::for_each_chunk,
+    Buf,
+};
+
+/// Lookup table for the reflected CRC-32 polynomial `0xedb88320`.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb88320
+            }
+            else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Incremental CRC-32 (IEEE 802.3) checksum.
+///
+/// # Example
+///
+/// ```
+/// # use byst::checksum::Crc32;
+/// let mut crc32 = Crc32::new();
+/// crc32.update(b"1234");
+/// crc32.update(b"56789");
+/// assert_eq!(crc32.finish(), 0xcbf43926);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Crc32 {
+    state: u32,
+}
+
+impl Crc32 {
+    #[inline]
+    pub fn new() -> Self {
+        Self { state: !0 }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state = CRC32_TABLE[usize::from((self.state as u8) ^ byte)] ^ (self.state >> 8);
+        }
+    }
+
+    #[inline]
+    pub fn update_buf(&mut self, buf: impl Buf) {
+        for_each_chunk(&mut buf.reader(), |chunk| self.update(chunk));
+    }
+
+    #[inline]
+    pub fn finish(&self) -> u32 {
+        !self.state
+    }
+}
+
+impl Default for Crc32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Multiplies the GF(2) 32x32 matrix `matrix` with the vector `vector`.
+fn gf2_matrix_times(matrix: &[u32; 32], mut vector: u32) -> u32 {
+    let mut sum = 0;
+    let mut i = 0;
+    while vector != 0 {
+        if vector & 1 != 0 {
+            sum ^= matrix[i];
+        }
+        vector >>= 1;
+        i += 1;
+    }
+    sum
+}
+
+/// Squares the GF(2) 32x32 matrix `matrix` into `square`.
+fn gf2_matrix_square(square: &mut [u32; 32], matrix: &[u32; 32]) {
+    for (row, column) in square.iter_mut().zip(matrix) {
+        *row = gf2_matrix_times(matrix, *column);
+    }
+}
+
+/// Combines the CRC-32 checksums of two consecutive buffers.
+///
+/// Given `crc1` of a first buffer, and `crc2` of a second buffer of length
+/// `len2`, this returns the checksum of both buffers concatenated. This runs
+/// in `O(log(len2))` and doesn't need the buffers themselves, so checksums of
+/// parts can be computed independently and combined afterwards.
+///
+/// This is the algorithm used by zlib's `crc32_combine`: Appending `len2`
+/// zero bytes to the first buffer is a linear operation on its CRC, which is
+/// applied by repeatedly squaring the operator for a single zero bit.
+///
+/// # Example
+///
+/// ```
+/// # use byst::{checksum::crc32_combine, buf::BufExt};
+/// let crc = crc32_combine(b"1234".crc32(), b"56789".crc32(), 5);
+/// assert_eq!(crc, b"123456789".crc32());
+/// ```
+pub fn crc32_combine(mut crc1: u32, crc2: u32, mut len2: usize) -> u32 {
+    if len2 == 0 {
+        return crc1;
+    }
+
+    // operator for a single zero bit
+    let mut odd = [0u32; 32];
+    odd[0] = 0xedb88320;
+    for (n, row) in odd.iter_mut().enumerate().skip(1) {
+        *row = 1 << (n - 1);
+    }
+
+    // operators for 2 and 4 zero bits
+    let mut even = [0u32; 32];
+    gf2_matrix_square(&mut even, &odd);
+    gf2_matrix_square(&mut odd, &even);
+
+    // apply `len2` zero bytes to `crc1`. the first square results in the operator
+    // for a single zero byte.
+    loop {
+        gf2_matrix_square(&mut even, &odd);
+        if len2 & 1 != 0 {
+            crc1 = gf2_matrix_times(&even, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+
+        gf2_matrix_square(&mut odd, &even);
+        if len2 & 1 != 0 {
+            crc1 = gf2_matrix_times(&odd, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+    }
+
+    crc1 ^ crc2
+}
+
+/// Largest prime smaller than 2^16.
+const ADLER32_MOD: u32 = 65521;
+
+/// Number of bytes we can sum before we have to reduce the sums to avoid
+/// overflows.
+const ADLER32_NMAX: usize = 5552;
+
+/// Incremental Adler-32 checksum.
+#[derive(Clone, Copy, Debug)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    #[inline]
+    pub fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(ADLER32_NMAX) {
+            for &byte in chunk {
+                self.a += u32::from(byte);
+                self.b += self.a;
+            }
+            self.a %= ADLER32_MOD;
+            self.b %= ADLER32_MOD;
+        }
+    }
+
+    #[inline]
+    pub fn update_buf(&mut self, buf: impl Buf) {
+        for_each_chunk(&mut buf.reader(), |chunk| self.update(chunk));
+    }
+
+    #[inline]
+    pub fn finish(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+impl Default for Adler32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        crc32_combine,
+        Adler32,
+        Crc32,
+    };
+    use crate::buf::BufExt;
+
+    #[test]
+    fn crc32_matches_test_vectors() {
+        assert_eq!(b"".crc32(), 0);
+        assert_eq!(b"123456789".crc32(), 0xcbf43926);
+        assert_eq!(
+            b"The quick brown fox jumps over the lazy dog".crc32(),
+            0x414fa339
+        );
+    }
+
+    #[test]
+    fn adler32_matches_test_vectors() {
+        assert_eq!(b"".adler32(), 1);
+        assert_eq!(b"Wikipedia".adler32(), 0x11e60398);
+        assert_eq!(vec![0xff; 100_000].adler32(), 0x149a302c);
+    }
+
+    #[test]
+    fn it_checksums_incrementally() {
+        let bytes = vec![0xab; 10_000];
+        let (left, right) = bytes.split_at(1234);
+
+        let mut crc32 = Crc32::new();
+        crc32.update_buf(left);
+        crc32.update_buf(right);
+        assert_eq!(crc32.finish(), bytes.crc32());
+
+        let mut adler32 = Adler32::new();
+        adler32.update_buf(left);
+        adler32.update_buf(right);
+        assert_eq!(adler32.finish(), bytes.adler32());
+    }
+
+    #[test]
+    fn crc32_combines() {
+        let bytes = (0..10_000u32).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        for at in [0, 1, 4, 1234, 9_999, 10_000] {
+            let (left, right) = bytes.split_at(at);
+            assert_eq!(
+                crc32_combine(left.crc32(), right.crc32(), right.len()),
+                bytes.crc32()
+            );
+        }
+    }
+}
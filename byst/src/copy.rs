@@ -1,7 +1,8 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use crate::{
     buf::{
+        arc_buf::ArcBufMut,
         Buf,
         BufMut,
         Full,
@@ -37,6 +38,47 @@ pub fn copy(mut destination: impl BufMut, source: impl Buf) -> Result<(), Full>
     Ok(())
 }
 
+/// Replaces the contents of `destination` with `source`, growing
+/// `destination` if it's too small.
+///
+/// Unlike [`copy`], this never fails. Afterwards `destination` contains
+/// exactly `source`, since it's truncated to the length of `source`.
+///
+/// If `source` doesn't fit into the destination's capacity, `destination` is
+/// replaced with a new buffer, whose capacity is at least doubled and that has
+/// the same headroom. The old contents aren't copied over, since they would be
+/// overwritten by `source` anyway. If `source` already fits, this doesn't
+/// allocate.
+///
+/// # Example
+///
+/// ```
+/// # use byst::{buf::arc_buf::ArcBufMut, copy_grow};
+/// let mut destination = ArcBufMut::new(4);
+/// copy_grow(&mut destination, b"Hello World");
+/// assert_eq!(destination, b"Hello World");
+/// assert!(destination.capacity() >= 11);
+/// ```
+pub fn copy_grow(destination: &mut ArcBufMut, source: impl Buf) {
+    let source_len = source.len();
+    if source_len > destination.capacity() {
+        // the old contents would be overwritten anyway, so don't copy them.
+        destination.clear();
+        destination.grow_to(source_len);
+    }
+    copy(&mut *destination, source).expect("Copying into a large enough buffer failed");
+    destination.truncate(source_len);
+}
+
+/// Copies up to `amount` bytes from `source` to `destination`.
+///
+/// Unlike [`copy`], this never fails. It copies as many bytes as are
+/// available in `source` and fit into `destination`, and returns the number
+/// of bytes copied.
+pub fn copy_n(mut destination: impl BufMut, source: impl Buf, amount: usize) -> usize {
+    copy_io(destination.writer(), source.reader(), amount)
+}
+
 /// Error while copying from a [`Buf`] to a [`BufMut`].
 #[derive(Debug, PartialEq, Eq, thiserror::Error)]
 #[error("Copy error")]
@@ -134,9 +176,9 @@ pub fn copy_io(
     while amount.map_or(true, |n| n > 0) {
         match (destination.peek_chunk_mut(), source.peek_chunk()) {
             (Some(dest_chunk), Some(src_chunk)) => {
-                let mut n = std::cmp::min(dest_chunk.len(), src_chunk.len());
+                let mut n = core::cmp::min(dest_chunk.len(), src_chunk.len());
                 if let Some(amount) = &mut amount {
-                    n = std::cmp::min(n, *amount);
+                    n = core::cmp::min(n, *amount);
                     *amount -= n;
                 }
 
@@ -151,7 +193,12 @@ pub fn copy_io(
                     .advance(n)
                     .expect("Expected at least {n} more bytes in BufReader");
             }
-            (None, Some(src_chunk)) => {
+            (None, Some(mut src_chunk)) => {
+                if let Some(amount) = &mut amount {
+                    src_chunk = &src_chunk[..core::cmp::min(src_chunk.len(), *amount)];
+                    *amount -= src_chunk.len();
+                }
+
                 if let Err(crate::io::Full { written, .. }) = destination.extend(src_chunk) {
                     // todo: we could try to fill any remaining bytes in the destination.
                     total_copied += written;
@@ -173,6 +220,29 @@ pub fn copy_io(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::counting_allocator::num_allocations;
+
+    #[test]
+    fn copy_grow_grows_destination() {
+        let source = (0..100).collect::<Vec<u8>>();
+        let mut destination = ArcBufMut::new(8);
+        copy_grow(&mut destination, &source);
+        assert!(destination.capacity() >= 100);
+        assert_eq!(destination, source);
+
+        let before = num_allocations();
+        copy_grow(&mut destination, b"Hello World");
+        assert_eq!(num_allocations(), before);
+        assert_eq!(destination, b"Hello World");
+    }
+
+    #[test]
+    fn copy_grow_keeps_headroom() {
+        let mut destination = ArcBufMut::with_headroom(4, 2);
+        copy_grow(&mut destination, b"Hello World");
+        assert_eq!(destination, b"Hello World");
+        assert_eq!(destination.headroom(), 2);
+    }
 
     #[test]
     fn fails_if_source_longer_than_destination() {
@@ -227,4 +297,41 @@ mod tests {
         assert_eq!(total_copied, 4);
         assert_eq!([1, 2, 3, 4, 42, 42, 42, 42], destination);
     }
+
+    #[test]
+    fn it_copies_partial_into_growing_destination() {
+        let mut destination = vec![];
+        let source: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        let total_copied = copy_io(destination.writer(), &mut source.as_ref(), Some(4));
+        assert_eq!(total_copied, 4);
+        assert_eq!([1, 2, 3, 4], *destination);
+    }
+
+    #[test]
+    fn copy_n_stops_at_end_of_source() {
+        let mut destination = [42; 8];
+        let source = [1, 2, 3];
+
+        assert_eq!(copy_n(&mut destination, source, 6), 3);
+        assert_eq!([1, 2, 3, 42, 42, 42, 42, 42], destination);
+    }
+
+    #[test]
+    fn copy_n_stops_at_end_of_destination() {
+        let mut destination = [42; 4];
+        let source = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        assert_eq!(copy_n(&mut destination, source, 6), 4);
+        assert_eq!([1, 2, 3, 4], destination);
+    }
+
+    #[test]
+    fn copy_n_copies_at_most_amount() {
+        let mut destination = vec![];
+        let source = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        assert_eq!(copy_n(&mut destination, source, 6), 6);
+        assert_eq!([1, 2, 3, 4, 5, 6], *destination);
+    }
 }
@@ -5,8 +5,17 @@
 //! - Remove `Size`, `Encode` and `Decode` traits as they require unstable
 //!   features, and we don't really need them.
 
+use core::num::{
+    NonZeroU128,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+};
+
 use crate::io::{
+    InvalidChar,
     Read,
+    ReadValueError,
     Reader,
     ReaderExt,
     Write,
@@ -152,10 +161,310 @@ impl_endianness! {
     f64: 8;
 }
 
+#[cfg(feature = "half")]
+impl_endianness! {
+    half::f16: 2;
+}
+
+impl Size for bool {
+    const BYTES: usize = 1;
+    const BITS: usize = 8;
+}
+
+impl<E: Endianness> Encode<E> for bool {
+    /// Encodes `false` as 0 and `true` as 1.
+    #[inline]
+    fn encode(&self) -> [u8; 1] {
+        [u8::from(*self)]
+    }
+}
+
+impl<E: Endianness> Decode<E> for bool {
+    /// Decodes 0 as `false` and any other value as `true`.
+    #[inline]
+    fn decode(bytes: &[u8; 1]) -> Self {
+        bytes[0] != 0
+    }
+}
+
+impl Size for char {
+    const BYTES: usize = 4;
+    const BITS: usize = 32;
+}
+
+// note: there is no `Decode` for `char`, since not all `u32`s are valid
+// `char`s.
+impl<E: Endianness> Encode<E> for char
+where
+    u32: Encode<E>,
+{
+    /// Encodes the `char`'s scalar value as an `u32`.
+    #[inline]
+    fn encode(&self) -> [u8; 4] {
+        <u32 as Encode<E>>::encode(&u32::from(*self))
+    }
+}
+
+impl<R: Reader, E: Endianness> Read<R, E> for char
+where
+    u32: Read<R, E, Error = <R as Reader>::Error>,
+{
+    type Error = ReadValueError<<R as Reader>::Error, InvalidChar>;
+
+    /// Reads the `char`'s scalar value as an `u32`.
+    ///
+    /// This fails with [`InvalidChar`] if the value is a surrogate or out of
+    /// range.
+    #[inline]
+    fn read(reader: &mut R, context: E) -> Result<Self, Self::Error> {
+        let value = reader.read_with::<u32, E>(context)?;
+        char::from_u32(value).ok_or(ReadValueError::Invalid {
+            value: InvalidChar(value),
+            read: 4,
+        })
+    }
+}
+
+impl<W: Writer, E: Endianness> Write<W, E> for char
+where
+    u32: Write<W, E, Error = <W as Writer>::Error>,
+{
+    type Error = <W as Writer>::Error;
+
+    /// Writes the `char`'s scalar value as an `u32`.
+    #[inline]
+    fn write(&self, writer: &mut W, context: E) -> Result<(), Self::Error> {
+        u32::from(*self).write(writer, context)
+    }
+}
+
+/// A non-zero integer, e.g. [`NonZeroU32`], was read as zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, thiserror::Error)]
+#[error("Value must not be zero")]
+pub struct ZeroValue;
+
+macro_rules! impl_nonzero {
+    ($($ty:ident => $inner:ty;)*) => {
+        $(
+            impl<R: Reader, E: Endianness> Read<R, E> for $ty
+            where
+                $inner: Read<R, E, Error = <R as Reader>::Error>,
+            {
+                type Error = ReadValueError<<R as Reader>::Error, ZeroValue>;
+
+                /// Reads the integer and fails with [`ZeroValue`] if it's
+                /// zero.
+                #[inline]
+                fn read(reader: &mut R, context: E) -> Result<Self, Self::Error> {
+                    let value = reader.read_with::<$inner, E>(context)?;
+                    <$ty>::new(value).ok_or(ReadValueError::Invalid {
+                        value: ZeroValue,
+                        read: core::mem::size_of::<$inner>(),
+                    })
+                }
+            }
+
+            impl<W: Writer, E: Endianness> Write<W, E> for $ty
+            where
+                $inner: Write<W, E, Error = <W as Writer>::Error>,
+            {
+                type Error = <W as Writer>::Error;
+
+                #[inline]
+                fn write(&self, writer: &mut W, context: E) -> Result<(), Self::Error> {
+                    self.get().write(writer, context)
+                }
+            }
+        )*
+    };
+}
+
+impl_nonzero! {
+    NonZeroU16 => u16;
+    NonZeroU32 => u32;
+    NonZeroU64 => u64;
+    NonZeroU128 => u128;
+}
+
+/// Maps a signed integer to an unsigned one, such that values with a small
+/// magnitude map to small values: 0 → 0, -1 → 1, 1 → 2, -2 → 3, ...
+#[inline]
+pub const fn zigzag_encode(value: i64) -> u64 {
+    // note: `value >> 63` is an arithmetic shift, so it's all ones for negative
+    // values.
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Inverse of [`zigzag_encode`].
+#[inline]
+pub const fn zigzag_decode(value: u64) -> i64 {
+    // note: `value >> 1` is a logical shift, since `value` is unsigned.
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Like [`zigzag_encode`], but for [`i32`].
+#[inline]
+pub const fn zigzag_encode_i32(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Like [`zigzag_decode`], but for [`u32`].
+#[inline]
+pub const fn zigzag_decode_i32(value: u32) -> i32 {
+    ((value >> 1) as i32) ^ -((value & 1) as i32)
+}
+
+/// A signed integer that is zigzag-encoded (see [`zigzag_encode`]) and then
+/// written as an unsigned [LEB128](https://en.wikipedia.org/wiki/LEB128).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignedVarint(pub i64);
+
+impl From<i64> for SignedVarint {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SignedVarint> for i64 {
+    #[inline]
+    fn from(value: SignedVarint) -> Self {
+        value.0
+    }
+}
+
+/// A [`SignedVarint`] was longer than 64 bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, thiserror::Error)]
+#[error("Varint is longer than 64 bits")]
+pub struct VarintOverflow;
+
+impl<R: Reader> Read<R, ()> for SignedVarint {
+    type Error = ReadValueError<<R as Reader>::Error, VarintOverflow>;
+
+    fn read(reader: &mut R, _context: ()) -> Result<Self, Self::Error> {
+        // a varint can only overflow on its 10th byte.
+        let overflow = || {
+            ReadValueError::Invalid {
+                value: VarintOverflow,
+                read: 10,
+            }
+        };
+
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = reader.read::<u8>()?;
+            let bits = u64::from(byte & 0x7f);
+            if shift == 63 && bits > 1 {
+                return Err(overflow());
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+            if shift > 63 {
+                return Err(overflow());
+            }
+        }
+        Ok(Self(zigzag_decode(value)))
+    }
+}
+
+impl<W: Writer> Write<W, ()> for SignedVarint {
+    type Error = <W as Writer>::Error;
+
+    fn write(&self, writer: &mut W, _context: ()) -> Result<(), Self::Error> {
+        let mut value = zigzag_encode(self.0);
+        let mut buf = [0; 10];
+        let mut n = 0;
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                buf[n] = byte;
+                n += 1;
+                break;
+            }
+            buf[n] = byte | 0x80;
+            n += 1;
+        }
+        writer.write_buf(&buf[..n])
+    }
+}
+
+/// A [`usize`] that is read and written as an [`u64`].
+///
+/// This gives it the same width on all platforms, so e.g. data written on a
+/// 64-bit platform can be read on a 32-bit one. Reading fails with
+/// [`UsizeOverflow`] if the value doesn't fit into a [`usize`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UsizeAsU64(pub usize);
+
+impl From<usize> for UsizeAsU64 {
+    #[inline]
+    fn from(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+impl From<UsizeAsU64> for usize {
+    #[inline]
+    fn from(value: UsizeAsU64) -> Self {
+        value.0
+    }
+}
+
+/// A [`UsizeAsU64`] was read, but doesn't fit into a [`usize`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, thiserror::Error)]
+#[error("Value doesn't fit into usize: {0}")]
+pub struct UsizeOverflow(pub u64);
+
+/// Converts a value read as `u64` into a narrower type, without truncating.
+#[inline]
+fn checked_from_u64<T: TryFrom<u64>>(value: u64) -> Result<T, UsizeOverflow> {
+    T::try_from(value).map_err(|_| UsizeOverflow(value))
+}
+
+impl<R: Reader, E: Endianness> Read<R, E> for UsizeAsU64
+where
+    u64: Read<R, E, Error = <R as Reader>::Error>,
+{
+    type Error = ReadValueError<<R as Reader>::Error, UsizeOverflow>;
+
+    #[inline]
+    fn read(reader: &mut R, context: E) -> Result<Self, Self::Error> {
+        let value = reader.read_with::<u64, E>(context)?;
+        checked_from_u64(value)
+            .map(Self)
+            .map_err(|value| ReadValueError::Invalid { value, read: 8 })
+    }
+}
+
+impl<W: Writer, E: Endianness> Write<W, E> for UsizeAsU64
+where
+    u64: Write<W, E, Error = <W as Writer>::Error>,
+{
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, context: E) -> Result<(), Self::Error> {
+        // note: `usize` is at most 64 bits wide on all supported platforms.
+        (self.0 as u64).write(writer, context)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hexdump::Hexdump;
+    use crate::{
+        buf::BufMut,
+        hexdump::Hexdump,
+        io::{
+            ReadError,
+            WriterExt,
+        },
+    };
 
     macro_rules! make_tests {
         {
@@ -249,4 +558,217 @@ got:      {:?}"#,
             b"\x21\x43\x65\x87\xa9\xcb\xed\x0f\xf0\xde\xbc\x9a\x78\x56\x34\x12"
         };
     }
+
+    #[test]
+    fn zigzag_maps_small_magnitudes_to_small_values() {
+        for (signed, unsigned) in [(0, 0), (-1, 1), (1, 2), (-2, 3), (2, 4)] {
+            assert_eq!(zigzag_encode(signed), unsigned);
+            assert_eq!(zigzag_decode(unsigned), signed);
+            assert_eq!(zigzag_encode_i32(signed as i32), unsigned as u32);
+            assert_eq!(zigzag_decode_i32(unsigned as u32), signed as i32);
+        }
+
+        assert_eq!(zigzag_encode(i64::MAX), u64::MAX - 1);
+        assert_eq!(zigzag_encode(i64::MIN), u64::MAX);
+        for value in [i64::MIN, i64::MAX] {
+            assert_eq!(zigzag_decode(zigzag_encode(value)), value);
+        }
+        for value in [i32::MIN, i32::MAX] {
+            assert_eq!(zigzag_decode_i32(zigzag_encode_i32(value)), value);
+        }
+    }
+
+    #[test]
+    fn signed_varint_round_trips() {
+        let values = [0, -1, 1, -64, 64, 300, -300, i64::MIN, i64::MAX];
+
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        for value in values {
+            writer.write(&SignedVarint(value)).unwrap();
+        }
+
+        let mut reader: &[u8] = &buf;
+        assert_eq!(&reader[..4], b"\x00\x01\x02\x7f");
+        for value in values {
+            assert_eq!(reader.read::<SignedVarint>().unwrap(), SignedVarint(value));
+        }
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn signed_varint_rejects_overflow() {
+        let mut reader: &[u8] = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x02";
+        assert_eq!(
+            reader.read::<SignedVarint>().unwrap_err(),
+            ReadValueError::Invalid {
+                value: VarintOverflow,
+                read: 10,
+            }
+        );
+
+        let mut reader: &[u8] = b"\x80\x80";
+        assert!(reader.read::<SignedVarint>().unwrap_err().is_end());
+    }
+
+    #[test]
+    fn nonzero_rejects_zero() {
+        let mut reader: &[u8] = b"\x00\x00\x12\x34\x00\x00\x00\x00";
+        assert_eq!(
+            reader.read_with::<NonZeroU32, _>(BigEndian).unwrap(),
+            NonZeroU32::new(0x1234).unwrap()
+        );
+        let error = reader.read_with::<NonZeroU32, _>(BigEndian).unwrap_err();
+        assert_eq!(
+            error,
+            ReadValueError::Invalid {
+                value: ZeroValue,
+                read: 4,
+            }
+        );
+        assert!(!error.is_end());
+        assert!(reader
+            .read_with::<NonZeroU16, _>(BigEndian)
+            .unwrap_err()
+            .is_end());
+
+        let mut buf = vec![];
+        buf.writer()
+            .write_with(&NonZeroU16::new(0x1234).unwrap(), LittleEndian)
+            .unwrap();
+        assert_eq!(buf, b"\x34\x12");
+    }
+
+    #[test]
+    fn usize_as_u64_round_trips() {
+        let mut buf = vec![];
+        buf.writer()
+            .write_with(&UsizeAsU64(0x12345678), BigEndian)
+            .unwrap();
+        assert_eq!(buf, b"\x00\x00\x00\x00\x12\x34\x56\x78");
+
+        let mut reader: &[u8] = &buf;
+        assert_eq!(
+            reader.read_with::<UsizeAsU64, _>(BigEndian).unwrap(),
+            UsizeAsU64(0x12345678)
+        );
+        assert!(reader
+            .read_with::<UsizeAsU64, _>(BigEndian)
+            .unwrap_err()
+            .is_end());
+    }
+
+    #[test]
+    fn usize_as_u64_rejects_overflow() {
+        // simulates reading on a platform with a 32-bit `usize`.
+        assert_eq!(checked_from_u64::<u32>(0xffff_ffff), Ok(0xffff_ffff));
+        assert_eq!(
+            checked_from_u64::<u32>(0x1_0000_0000),
+            Err(UsizeOverflow(0x1_0000_0000))
+        );
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn f16_round_trips() {
+        use half::f16;
+
+        let values = [
+            f16::ONE,
+            f16::NEG_ONE,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f16::from_bits(0x03ff),
+            f16::INFINITY,
+            f16::NEG_INFINITY,
+            f16::NAN,
+            f16::from_bits(0x7c01),
+            f16::from_bits(0xfe42),
+        ];
+
+        assert_eq!(<f16 as Encode<BigEndian>>::encode(&f16::ONE), *b"\x3c\x00");
+        assert_eq!(
+            <f16 as Encode<LittleEndian>>::encode(&f16::ONE),
+            *b"\x00\x3c"
+        );
+
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        for value in values {
+            writer.write_with(&value, BigEndian).unwrap();
+            writer.write_with(&value, LittleEndian).unwrap();
+        }
+
+        let mut reader: &[u8] = &buf;
+        for value in values {
+            let got = reader.read_with::<f16, _>(BigEndian).unwrap();
+            assert_eq!(got.to_bits(), value.to_bits());
+            let got = reader.read_with::<f16, _>(LittleEndian).unwrap();
+            assert_eq!(got.to_bits(), value.to_bits());
+        }
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn bool_round_trips() {
+        assert_eq!(<bool as Encode<BigEndian>>::encode(&true), [1]);
+        assert_eq!(<bool as Encode<BigEndian>>::encode(&false), [0]);
+        assert!(<bool as Decode<LittleEndian>>::decode(&[0x42]));
+        assert!(!<bool as Decode<LittleEndian>>::decode(&[0]));
+
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        writer.write(&true).unwrap();
+        writer.write(&false).unwrap();
+        assert_eq!(buf, b"\x01\x00");
+
+        let mut reader: &[u8] = b"\x00\x01\xff";
+        assert!(!reader.read::<bool>().unwrap());
+        assert!(reader.read::<bool>().unwrap());
+        assert!(reader.read::<bool>().unwrap());
+    }
+
+    #[test]
+    fn char_round_trips() {
+        assert_eq!(
+            <char as Encode<BigEndian>>::encode(&'\u{1f980}'),
+            *b"\x00\x01\xf9\x80"
+        );
+
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        writer.write_with(&'\u{1f980}', LittleEndian).unwrap();
+        writer.write_with(&'A', BigEndian).unwrap();
+        assert_eq!(buf, b"\x80\xf9\x01\x00\x00\x00\x00\x41");
+
+        let mut reader: &[u8] = &buf;
+        assert_eq!(
+            reader.read_with::<char, _>(LittleEndian).unwrap(),
+            '\u{1f980}'
+        );
+        assert_eq!(reader.read_with::<char, _>(BigEndian).unwrap(), 'A');
+    }
+
+    #[test]
+    fn invalid_char_is_rejected() {
+        let mut reader: &[u8] = b"\x00\x00\xd8\x00";
+        assert_eq!(
+            reader.read_with::<char, _>(BigEndian).unwrap_err(),
+            ReadValueError::Invalid {
+                value: InvalidChar(0xd800),
+                read: 4,
+            }
+        );
+
+        let mut reader: &[u8] = b"\x00\x11\x00\x00";
+        assert_eq!(
+            reader.read_with::<char, _>(BigEndian).unwrap_err(),
+            ReadValueError::Invalid {
+                value: InvalidChar(0x110000),
+                read: 4,
+            }
+        );
+
+        let mut reader: &[u8] = b"\x00\x00";
+        assert!(reader.read_with::<char, _>(BigEndian).unwrap_err().is_end());
+    }
 }
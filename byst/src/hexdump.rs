@@ -1,4 +1,4 @@
-use std::fmt::{
+use core::fmt::{
     Debug,
     Display,
     Write as _,
@@ -33,7 +33,7 @@ impl<B> Hexdump<B> {
 }
 
 impl<B: Buf> Display for Hexdump<B> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut lines = Lines::new(&self.buf, &self.config);
 
         if self.config.header {
@@ -57,7 +57,7 @@ impl<B: Buf> Display for Hexdump<B> {
 }
 
 impl<B: Buf> Debug for Hexdump<B> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let hex = Hexdump {
             buf: &self.buf,
             config: Config {
@@ -72,6 +72,101 @@ impl<B: Buf> Debug for Hexdump<B> {
     }
 }
 
+/// Returns a side-by-side hexdump of `a` and `b`, that marks where they
+/// differ.
+///
+/// See [`HexdumpDiff`] for details.
+#[inline]
+pub fn hexdump_diff<A, B>(a: A, b: B) -> HexdumpDiff<A, B> {
+    HexdumpDiff { a, b }
+}
+
+/// Side-by-side hexdump of two buffers.
+///
+/// Each line shows 16 bytes of `a` on the left, and the bytes at the same
+/// offsets in `b` on the right. Lines that differ start with `!`. Each byte is
+/// preceded by a marker:
+///
+/// - `*`: The byte differs between `a` and `b`.
+/// - `-`: The byte is only in `a` (i.e. `b` is shorter).
+/// - `+`: The byte is only in `b` (i.e. `a` is shorter).
+///
+/// The shorter buffer is padded with blanks.
+///
+/// ```plain
+/// ! 0000  48 65 6c 6c 6f 20*57 6f 72 6c 64-21             | 48 65 6c 6c 6f 20*77 6f 72 6c 64
+/// ```
+pub struct HexdumpDiff<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Buf, B: Buf> Display for HexdumpDiff<A, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let a_len = self.a.len();
+        let b_len = self.b.len();
+        let len = core::cmp::max(a_len, b_len);
+        let pad_offset_to = core::cmp::max(num_hex_digits(len), 4);
+
+        let mut a_reader = self.a.reader();
+        let mut b_reader = self.b.reader();
+
+        for offset in (0..len).step_by(16) {
+            let mut a_line = [0; 16];
+            let a_num_bytes = copy_io(a_line.writer(), &mut a_reader, 16);
+            let mut b_line = [0; 16];
+            let b_num_bytes = copy_io(b_line.writer(), &mut b_reader, 16);
+
+            let differs = a_num_bytes != b_num_bytes || a_line != b_line;
+
+            if offset > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{} ", if differs { '!' } else { ' ' })?;
+            for _ in 0..(pad_offset_to - num_hex_digits(offset)) {
+                write!(f, "0")?;
+            }
+            write!(f, "{offset:x} ")?;
+
+            write_diff_side(f, &a_line[..a_num_bytes], &b_line[..b_num_bytes], '-')?;
+            for _ in a_num_bytes..16 {
+                write!(f, "   ")?;
+            }
+            write!(f, " |")?;
+            write_diff_side(f, &b_line[..b_num_bytes], &a_line[..a_num_bytes], '+')?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes one side of a line of a [`HexdumpDiff`].
+///
+/// Bytes that are not in `other` are marked with `only_marker`.
+fn write_diff_side(
+    f: &mut core::fmt::Formatter<'_>,
+    line: &[u8],
+    other: &[u8],
+    only_marker: char,
+) -> core::fmt::Result {
+    for (i, b) in line.iter().enumerate() {
+        let marker = match other.get(i) {
+            None => only_marker,
+            Some(other) if other != b => '*',
+            Some(_) => ' ',
+        };
+        write!(f, "{marker}{b:02x}")?;
+    }
+    Ok(())
+}
+
+impl<A: Buf, B: Buf> Debug for HexdumpDiff<A, B> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct Config {
     pub offset: usize,
@@ -101,7 +196,7 @@ pub struct Lines<'b, B: Buf + 'b> {
 
 impl<'b, B: Buf> Lines<'b, B> {
     pub fn new(buf: &'b B, config: &Config) -> Self {
-        let pad_offset_to = std::cmp::max(num_hex_digits(config.offset + buf.len()), 4);
+        let pad_offset_to = core::cmp::max(num_hex_digits(config.offset + buf.len()), 4);
         Self {
             reader: buf.reader(),
             pad_offset_to,
@@ -144,7 +239,7 @@ pub struct Line {
 }
 
 impl Display for Line {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         // print offset
         for _ in 0..(self.pad_offset_to - num_hex_digits(self.offset)) {
             write!(f, "0")?;
@@ -194,7 +289,59 @@ fn num_hex_digits(mut num: usize) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use super::Hexdump;
+    use super::{
+        hexdump_diff,
+        Hexdump,
+    };
+    use crate::{
+        buf::segments::Segments,
+        Buf,
+        Bytes,
+    };
+
+    #[test]
+    fn buf_hexdump_matches_hexdump() {
+        let bytes = Bytes::from_static(b"Hello World, this is a test.");
+        assert_eq!(
+            bytes.hexdump().to_string(),
+            Hexdump::new(bytes.contiguous().unwrap()).to_string()
+        );
+    }
+
+    #[test]
+    fn buf_hexdump_walks_chunks() {
+        let segments: &[&[u8]] = &[b"Hello World, ", b"this is a test."];
+        assert_eq!(
+            Segments::new(segments).hexdump().to_string(),
+            Hexdump::new(b"Hello World, this is a test.").to_string()
+        );
+    }
+
+    #[test]
+    fn diff_of_identical_buffers_has_no_markers() {
+        let data = b"Hello World, this is a test.";
+        let formatted = hexdump_diff(data, data).to_string();
+        assert!(!formatted.contains(['!', '*', '-', '+']));
+        assert_eq!(formatted.lines().count(), 2);
+    }
+
+    #[test]
+    fn diff_marks_changed_byte() {
+        let formatted = hexdump_diff(b"Hello World", b"Hello world").to_string();
+        let expected =
+            "! 0000  48 65 6c 6c 6f 20*57 6f 72 6c 64                | 48 65 6c 6c 6f 20*77 6f 72 6c 64";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn diff_marks_tail_of_longer_buffer() {
+        let formatted = hexdump_diff(b"Hello!", b"Hello").to_string();
+        assert!(formatted.starts_with("! 0000  48 65 6c 6c 6f-21"));
+        assert!(formatted.ends_with("| 48 65 6c 6c 6f"));
+
+        let formatted = hexdump_diff(b"Hello", b"Hello!").to_string();
+        assert!(formatted.ends_with("| 48 65 6c 6c 6f+21"));
+    }
 
     #[test]
     fn test_display() {
@@ -0,0 +1,189 @@
+use super::Writer;
+use crate::{
+    buf::{
+        arc_buf::ArcBufMut,
+        Length,
+    },
+    Buf,
+    BufMut,
+};
+
+/// A writer that batches small writes into an internal buffer, before
+/// passing them on to an inner writer.
+///
+/// This is useful when writing many small values to a writer for which each
+/// call is expensive, e.g. a socket or file. Writes are collected in a buffer
+/// of fixed capacity, which is written to the inner writer once it can't hold
+/// the next write, or when [`flush`](Self::flush) is called. Writes that
+/// don't fit into an empty buffer are passed on directly. This is similar to
+/// [`std::io::BufWriter`].
+///
+/// When dropped, the buffered bytes are flushed, but errors are ignored. Call
+/// [`flush`](Self::flush) explicitly to handle them.
+///
+/// This is created with [`WriterExt::buffered`](super::WriterExt::buffered).
+pub struct BufferedWriter<W: Writer> {
+    inner: W,
+    buf: ArcBufMut,
+}
+
+impl<W: Writer> BufferedWriter<W> {
+    /// Creates a buffered writer with an internal buffer that holds up to
+    /// `capacity` bytes.
+    #[inline]
+    pub fn new(inner: W, capacity: usize) -> Self {
+        Self {
+            inner,
+            buf: ArcBufMut::new(capacity),
+        }
+    }
+
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly bypasses the buffered bytes, so you might want
+    /// to [`flush`](Self::flush) first.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the bytes that are buffered, but not written yet.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Writes all buffered bytes to the inner writer.
+    ///
+    /// If this fails, the buffered bytes are kept.
+    pub fn flush(&mut self) -> Result<(), W::Error> {
+        if !self.buf.is_empty() {
+            self.inner.write_buf(&self.buf)?;
+            self.buf.clear();
+        }
+        Ok(())
+    }
+}
+
+impl<W: Writer> Writer for BufferedWriter<W> {
+    type Error = W::Error;
+
+    fn write_buf<B: Buf>(&mut self, buf: B) -> Result<(), W::Error> {
+        let length = buf.len();
+        if length > self.buf.capacity() - self.buf.len() {
+            self.flush()?;
+        }
+
+        if length >= self.buf.capacity() {
+            self.inner.write_buf(buf)
+        }
+        else {
+            self.buf
+                .put(buf)
+                .expect("Expected the write to fit into the buffer");
+            Ok(())
+        }
+    }
+
+    /// Flushes the buffered bytes and then skips `amount` bytes of the inner
+    /// writer.
+    #[inline]
+    fn skip(&mut self, amount: usize) -> Result<(), W::Error> {
+        self.flush()?;
+        self.inner.skip(amount)
+    }
+}
+
+impl<W: Writer> Drop for BufferedWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+impl<W: Writer + core::fmt::Debug> core::fmt::Debug for BufferedWriter<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BufferedWriter")
+            .field("inner", &self.inner)
+            .field("buffered", &self.buf.len())
+            .field("capacity", &self.buf.capacity())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::{
+        buf::BufExt,
+        io::{
+            Writer,
+            WriterExt,
+        },
+        Buf,
+    };
+
+    /// Records the length of each write.
+    #[derive(Debug, Default)]
+    struct Recorder {
+        data: Vec<u8>,
+        writes: Vec<usize>,
+    }
+
+    impl Writer for Recorder {
+        type Error = Infallible;
+
+        fn write_buf<B: Buf>(&mut self, buf: B) -> Result<(), Infallible> {
+            self.writes.push(buf.len());
+            self.data.extend(buf.as_vec());
+            Ok(())
+        }
+
+        fn skip(&mut self, amount: usize) -> Result<(), Infallible> {
+            self.data.resize(self.data.len() + amount, 0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn it_batches_small_writes() {
+        let mut sink = Recorder::default();
+        let mut writer = sink.buffered(8);
+        for i in 0..10u8 {
+            writer.write_buf([i, i]).unwrap();
+        }
+        assert_eq!(writer.buffer(), [8, 8, 9, 9]);
+        writer.flush().unwrap();
+        assert!(writer.buffer().is_empty());
+        drop(writer);
+
+        assert_eq!(sink.writes, [8, 8, 4]);
+        assert_eq!(
+            sink.data,
+            (0..10u8).flat_map(|i| [i, i]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn it_writes_large_writes_directly_and_flushes_on_drop() {
+        let mut sink = Recorder::default();
+        {
+            let mut writer = sink.buffered(4);
+            writer.write_buf(b"ab" as &[u8]).unwrap();
+            writer.write_buf(b"Hello World" as &[u8]).unwrap();
+            writer.write_buf(b"!" as &[u8]).unwrap();
+        }
+        assert_eq!(sink.writes, [2, 11, 1]);
+        assert_eq!(sink.data, b"abHello World!");
+    }
+}
@@ -0,0 +1,453 @@
+#[cfg(feature = "std")]
+use std::io::IoSlice;
+
+use super::{
+    BufReader,
+    End,
+    Seek,
+};
+use crate::{
+    buf::{
+        arc_buf::ArcBufMut,
+        Length,
+    },
+    copy_io,
+    impl_me,
+    Buf,
+    BufMut,
+    Bytes,
+    Range,
+    RangeOutOfBounds,
+};
+
+/// A reader that first reads from `A`, and then from `B`.
+///
+/// This is created with [`BufReader::chain`].
+#[derive(Clone, Debug)]
+pub struct ChainReader<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> ChainReader<A, B> {
+    #[inline]
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    #[inline]
+    pub fn first_ref(&self) -> &A {
+        &self.first
+    }
+
+    #[inline]
+    pub fn second_ref(&self) -> &B {
+        &self.second
+    }
+
+    /// Returns both inner readers. If the first reader has been exhausted, it
+    /// will be at its end.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: BufReader, B: BufReader> ChainReader<A, B> {
+    fn end(&self, requested: usize) -> End {
+        End {
+            read: 0,
+            requested,
+            remaining: self.remaining(),
+        }
+    }
+}
+
+impl<A: BufReader, B: BufReader> BufReader for ChainReader<A, B> {
+    type View = ChainView<A::View, B::View>;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        self.first.peek_chunk().or_else(|| self.second.peek_chunk())
+    }
+
+    #[cfg(feature = "std")]
+    fn peek_io_slices<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let mut n = self.first.peek_io_slices(dst);
+        let first_length = dst[..n].iter().map(|slice| slice.len()).sum::<usize>();
+        if first_length == self.first.remaining() {
+            n += self.second.peek_io_slices(&mut dst[n..]);
+        }
+        n
+    }
+
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        let first_remaining = self.first.remaining();
+
+        if length <= first_remaining {
+            Ok(ChainView::First(self.first.view(length)?))
+        }
+        else if first_remaining == 0 {
+            Ok(ChainView::Second(self.second.view(length)?))
+        }
+        else if length <= self.remaining() {
+            // the view straddles both readers, so we have to copy it into a contiguous
+            // buffer.
+            let second = self.second.view(length - first_remaining)?;
+            let first = self.first.rest();
+            Ok(ChainView::Copied(copy_straddling(first, second)))
+        }
+        else {
+            Err(self.end(length))
+        }
+    }
+
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        let first_remaining = self.first.remaining();
+
+        if length <= first_remaining {
+            Ok(ChainView::First(self.first.peek_view(length)?))
+        }
+        else if first_remaining == 0 {
+            Ok(ChainView::Second(self.second.peek_view(length)?))
+        }
+        else if length <= self.remaining() {
+            let first = self.first.peek_rest();
+            let second = self.second.peek_view(length - first_remaining)?;
+            Ok(ChainView::Copied(copy_straddling(first, second)))
+        }
+        else {
+            Err(self.end(length))
+        }
+    }
+
+    fn rest(&mut self) -> Self::View {
+        if self.first.remaining() == 0 {
+            ChainView::Second(self.second.rest())
+        }
+        else if self.second.remaining() == 0 {
+            ChainView::First(self.first.rest())
+        }
+        else {
+            ChainView::Copied(copy_straddling(self.first.rest(), self.second.rest()))
+        }
+    }
+
+    fn peek_rest(&self) -> Self::View {
+        if self.first.remaining() == 0 {
+            ChainView::Second(self.second.peek_rest())
+        }
+        else if self.second.remaining() == 0 {
+            ChainView::First(self.first.peek_rest())
+        }
+        else {
+            ChainView::Copied(copy_straddling(
+                self.first.peek_rest(),
+                self.second.peek_rest(),
+            ))
+        }
+    }
+
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        let first_remaining = self.first.remaining();
+
+        if by <= first_remaining {
+            self.first.advance(by)
+        }
+        else if by <= self.remaining() {
+            self.first.advance(first_remaining)?;
+            self.second.advance(by - first_remaining)
+        }
+        else {
+            Err(self.end(by))
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.first.remaining() + self.second.remaining()
+    }
+}
+
+impl<A: Seek, B: Seek> Seek for ChainReader<A, B> {
+    type Position = ChainReader<A::Position, B::Position>;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        ChainReader {
+            first: self.first.tell(),
+            second: self.second.tell(),
+        }
+    }
+
+    #[inline]
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        ChainReader {
+            first: self.first.seek(&position.first),
+            second: self.second.seek(&position.second),
+        }
+    }
+}
+
+/// Copies two views into a single contiguous [`Bytes`].
+fn copy_straddling(first: impl Buf, second: impl Buf) -> Bytes {
+    let length = first.len() + second.len();
+    let mut buf = ArcBufMut::new(length);
+    let mut writer = buf.writer();
+    let n_copied =
+        copy_io(&mut writer, first.reader(), None) + copy_io(&mut writer, second.reader(), None);
+    assert_eq!(
+        n_copied, length,
+        "Expected to copy {length} bytes, but copied {n_copied}."
+    );
+    buf.into()
+}
+
+/// A view returned by a [`ChainReader`].
+///
+/// Views that lie completely in one of the readers are returned as is. Views
+/// that straddle both readers are copied into a [`Bytes`].
+#[derive(Clone, Debug)]
+pub enum ChainView<A, B> {
+    First(A),
+    Second(B),
+    Copied(Bytes),
+}
+
+impl<A: Length, B: Length> Length for ChainView<A, B> {
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Self::First(first) => first.len(),
+            Self::Second(second) => second.len(),
+            Self::Copied(copied) => copied.len(),
+        }
+    }
+}
+
+impl<A: Buf, B: Buf> Buf for ChainView<A, B> {
+    type View<'a> = ChainView<A::View<'a>, B::View<'a>>
+    where
+        Self: 'a;
+
+    type Reader<'a> = ChainView<A::Reader<'a>, B::Reader<'a>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn view(&self, range: impl Into<Range>) -> Result<Self::View<'_>, RangeOutOfBounds> {
+        Ok(match self {
+            Self::First(first) => ChainView::First(first.view(range)?),
+            Self::Second(second) => ChainView::Second(second.view(range)?),
+            Self::Copied(copied) => ChainView::Copied(copied.view(range)?),
+        })
+    }
+
+    #[inline]
+    fn reader(&self) -> Self::Reader<'_> {
+        match self {
+            Self::First(first) => ChainView::First(first.reader()),
+            Self::Second(second) => ChainView::Second(second.reader()),
+            Self::Copied(copied) => ChainView::Copied(copied.reader()),
+        }
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        match self {
+            Self::First(first) => first.is_contiguous(),
+            Self::Second(second) => second.is_contiguous(),
+            Self::Copied(copied) => copied.is_contiguous(),
+        }
+    }
+}
+
+impl<A: BufReader, B: BufReader> BufReader for ChainView<A, B> {
+    type View = ChainView<A::View, B::View>;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        match self {
+            Self::First(first) => first.peek_chunk(),
+            Self::Second(second) => second.peek_chunk(),
+            Self::Copied(copied) => copied.peek_chunk(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn peek_io_slices<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        match self {
+            Self::First(first) => first.peek_io_slices(dst),
+            Self::Second(second) => second.peek_io_slices(dst),
+            Self::Copied(copied) => copied.peek_io_slices(dst),
+        }
+    }
+
+    #[inline]
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        Ok(match self {
+            Self::First(first) => ChainView::First(first.view(length)?),
+            Self::Second(second) => ChainView::Second(second.view(length)?),
+            Self::Copied(copied) => ChainView::Copied(BufReader::view(copied, length)?),
+        })
+    }
+
+    #[inline]
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        Ok(match self {
+            Self::First(first) => ChainView::First(first.peek_view(length)?),
+            Self::Second(second) => ChainView::Second(second.peek_view(length)?),
+            Self::Copied(copied) => ChainView::Copied(copied.peek_view(length)?),
+        })
+    }
+
+    #[inline]
+    fn rest(&mut self) -> Self::View {
+        match self {
+            Self::First(first) => ChainView::First(first.rest()),
+            Self::Second(second) => ChainView::Second(second.rest()),
+            Self::Copied(copied) => ChainView::Copied(copied.rest()),
+        }
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        match self {
+            Self::First(first) => ChainView::First(first.peek_rest()),
+            Self::Second(second) => ChainView::Second(second.peek_rest()),
+            Self::Copied(copied) => ChainView::Copied(copied.peek_rest()),
+        }
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        match self {
+            Self::First(first) => first.advance(by),
+            Self::Second(second) => second.advance(by),
+            Self::Copied(copied) => copied.advance(by),
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        match self {
+            Self::First(first) => first.remaining(),
+            Self::Second(second) => second.remaining(),
+            Self::Copied(copied) => copied.remaining(),
+        }
+    }
+}
+
+impl<A: Seek, B: Seek> Seek for ChainView<A, B> {
+    type Position = ChainView<A::Position, B::Position>;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        match self {
+            Self::First(first) => ChainView::First(first.tell()),
+            Self::Second(second) => ChainView::Second(second.tell()),
+            Self::Copied(copied) => ChainView::Copied(copied.tell()),
+        }
+    }
+
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        match (self, position) {
+            (Self::First(first), ChainView::First(position)) => {
+                ChainView::First(first.seek(position))
+            }
+            (Self::Second(second), ChainView::Second(position)) => {
+                ChainView::Second(second.seek(position))
+            }
+            (Self::Copied(copied), ChainView::Copied(position)) => {
+                ChainView::Copied(copied.seek(position))
+            }
+            _ => panic!("Position is from a different variant of the ChainView"),
+        }
+    }
+}
+
+impl<A: Buf, B: Buf, T: Buf> PartialEq<T> for ChainView<A, B> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        crate::util::buf_eq(self, other)
+    }
+}
+
+impl_me! {
+    impl[A: BufReader, B: BufReader] Reader for ChainReader<A, B> as BufReader;
+    impl[A: BufReader, B: BufReader] Reader for ChainView<A, B> as BufReader;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::io::IoSlice;
+
+    use super::ChainView;
+    use crate::{
+        endianness::BigEndian,
+        io::{
+            BufReader,
+            ReaderExt,
+        },
+    };
+
+    #[test]
+    fn it_reads_value_spanning_both_readers() {
+        let mut reader = (b"\x12\x34\x56" as &[u8]).chain(b"\x78\x9a" as &[u8]);
+        let value = reader.read_with::<u32, _>(BigEndian).unwrap();
+        assert_eq!(value, 0x12345678);
+        assert_eq!(reader.remaining(), 1);
+        assert_eq!(reader.read::<u8>().unwrap(), 0x9a);
+        assert!(reader.read::<u8>().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_exposes_both_chunks_as_io_slices() {
+        let first: &'static [u8] = b"Hello";
+        let second: &'static [u8] = b" World";
+        let mut reader = first.chain(second);
+
+        let mut slices = [IoSlice::new(&[]); 4];
+        assert_eq!(reader.peek_io_slices(&mut slices), 2);
+        assert_eq!(slices[0].as_ptr(), first.as_ptr());
+        assert_eq!(slices[0].len(), 5);
+        assert_eq!(slices[1].as_ptr(), second.as_ptr());
+        assert_eq!(slices[1].len(), 6);
+
+        reader.advance(2).unwrap();
+        let mut slices = [IoSlice::new(&[]); 1];
+        assert_eq!(reader.peek_io_slices(&mut slices), 1);
+        assert_eq!(slices[0].as_ptr(), first[2..].as_ptr());
+        assert_eq!(slices[0].len(), 3);
+    }
+
+    #[test]
+    fn it_copies_views_that_straddle_the_seam() {
+        let mut reader = (b"Hello" as &[u8]).chain(b" World" as &[u8]);
+
+        let view = reader.view(3).unwrap();
+        assert!(matches!(view, ChainView::First(b"Hel")));
+
+        let view = reader.view(4).unwrap();
+        assert!(matches!(view, ChainView::Copied(_)));
+        assert_eq!(view, b"lo W");
+
+        let view = reader.rest();
+        assert!(matches!(view, ChainView::Second(b"orld")));
+    }
+
+    #[test]
+    fn it_fails_to_advance_past_end() {
+        let mut reader = (b"ab" as &[u8]).chain(b"cd" as &[u8]);
+        assert!(reader.advance(5).is_err());
+        assert_eq!(reader.remaining(), 4);
+        reader.advance(3).unwrap();
+        assert_eq!(reader.peek_chunk(), Some(b"d" as &[u8]));
+        let (first, second) = reader.into_inner();
+        assert!(first.is_empty());
+        assert_eq!(second, b"d");
+    }
+}
@@ -0,0 +1,63 @@
+use super::BufReader;
+
+/// An iterator over the contiguous chunks of a reader.
+///
+/// Each item is a view of a single chunk, as returned by
+/// [`BufReader::peek_chunk`]. For a `&[u8]` reader the items are the slices
+/// themselves.
+///
+/// This is created with [`BufReader::chunks`].
+#[derive(Clone, Debug)]
+pub struct Chunks<R> {
+    reader: R,
+}
+
+impl<R> Chunks<R> {
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+}
+
+impl<R: BufReader> Iterator for Chunks<R> {
+    type Item = R::View;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let length = self.reader.peek_chunk()?.len();
+        Some(
+            self.reader
+                .view(length)
+                .expect("Expected to be able to view the whole chunk"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        buf::Length,
+        io::BufReader,
+        Bytes,
+    };
+
+    #[test]
+    fn single_chunk_yields_whole_buffer() {
+        let reader: &[u8] = b"Hello World";
+        let chunks = BufReader::chunks(&reader).collect::<Vec<_>>();
+        assert_eq!(chunks, [b"Hello World"]);
+        assert_eq!(reader, b"Hello World");
+    }
+
+    #[test]
+    fn it_yields_each_chunk() {
+        let reader =
+            Bytes::from(b"Hello" as &'static [u8]).chain(Bytes::from(b" World" as &'static [u8]));
+        let chunks = reader.chunks().collect::<Vec<_>>();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0], b"Hello");
+        assert_eq!(chunks[1], b" World");
+        assert_eq!(reader.remaining(), 11);
+        assert!(reader.chunks().all(|chunk| chunk.len() > 0));
+    }
+}
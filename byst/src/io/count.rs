@@ -10,7 +10,15 @@ use crate::buf::{
     Length,
 };
 
-#[derive(Clone, Debug)]
+/// A reader that counts how many bytes have been read from it.
+///
+/// This counts bytes consumed through any method, e.g. [`BufReader::view`],
+/// [`BufReader::advance`], [`BufReader::rest`], or [`Reader::read_into`]. It
+/// is created with [`ReaderExt::counted`](super::ReaderExt::counted).
+///
+/// If `R` implements [`Default`], e.g. [`Bytes`](crate::Bytes), so does
+/// `Count<R>`. The default starts with a count of 0.
+#[derive(Clone, Debug, Default)]
 pub struct Count<R> {
     inner: R,
     count: usize,
@@ -145,11 +153,58 @@ impl<R: Seek> Seek for Count<R> {
 
     #[inline]
     fn seek(&mut self, position: &Self::Position) -> Self::Position {
-        let position = Count {
+        let old_position = Count {
             inner: self.inner.seek(&position.inner),
             count: self.count,
         };
         self.count = position.count;
-        position
+        old_position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Count;
+    use crate::{
+        buf::arc_buf::ArcBuf,
+        io::{
+            BufReader,
+            ReaderExt,
+            Seek,
+        },
+        Bytes,
+    };
+
+    #[test]
+    fn default_is_empty() {
+        #[derive(Default)]
+        struct Parser {
+            input: Count<ArcBuf>,
+        }
+
+        let parser = Parser::default();
+        assert_eq!(parser.input.count(), 0);
+        assert_eq!(parser.input.remaining(), 0);
+        assert_eq!(Count::<Bytes>::default().remaining(), 0);
+    }
+
+    #[test]
+    fn it_counts_bytes_consumed_by_all_methods() {
+        let mut reader = (b"Hello World!" as &[u8]).counted();
+
+        reader.view(2).unwrap();
+        assert_eq!(reader.count(), 2);
+        reader.advance(3).unwrap();
+        assert_eq!(reader.count(), 5);
+        reader.peek_view(2).unwrap();
+        assert_eq!(reader.count(), 5);
+        reader.read::<u8>().unwrap();
+        assert_eq!(reader.count(), 6);
+
+        let position = reader.tell();
+        reader.rest();
+        assert_eq!(reader.count(), 12);
+        reader.seek(&position);
+        assert_eq!(reader.count(), 6);
     }
 }
@@ -0,0 +1,224 @@
+use super::{
+    End,
+    Reader,
+};
+use crate::{
+    buf::Length,
+    BytesMut,
+};
+
+/// Decodes frames from a stream of bytes.
+///
+/// This is used by [`FramedRead`] to turn the bytes it reads into a sequence
+/// of frames.
+pub trait Decoder {
+    type Item;
+    type Error: From<End>;
+
+    /// Decodes a frame from the start of `src`.
+    ///
+    /// If `src` contains a full frame, the decoder removes the frame's bytes
+    /// from `src` (e.g. with [`BytesMut::advance`]) and returns it. Otherwise
+    /// it returns `Ok(None)`, and is called again once more bytes are
+    /// available.
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error>;
+
+    /// Decodes a frame when no more bytes will become available.
+    ///
+    /// By default this calls [`Decoder::decode`], and fails with [`End`], if
+    /// there are bytes left in `src` that don't form a full frame.
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(item) => Ok(Some(item)),
+            None if src.is_empty() => Ok(None),
+            None => {
+                Err(End {
+                    read: src.len(),
+                    requested: src.len() + 1,
+                    remaining: 0,
+                }
+                .into())
+            }
+        }
+    }
+}
+
+impl<'a, D: Decoder> Decoder for &'a mut D {
+    type Item = D::Item;
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        D::decode(*self, src)
+    }
+
+    #[inline]
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        D::decode_eof(*self, src)
+    }
+}
+
+/// Number of bytes [`FramedRead`] reads from its reader at once.
+const READ_SIZE: usize = 4096;
+
+/// Reads frames from a [`Reader`] using a [`Decoder`].
+///
+/// Bytes are read into an internal [`BytesMut`] until the decoder returns a
+/// frame. This can be used as an [`Iterator`] over the frames.
+#[derive(Debug)]
+pub struct FramedRead<R, D> {
+    reader: R,
+    decoder: D,
+    buffer: BytesMut,
+    eof: bool,
+}
+
+impl<R, D> FramedRead<R, D> {
+    #[inline]
+    pub fn new(reader: R, decoder: D) -> Self {
+        Self {
+            reader,
+            decoder,
+            buffer: BytesMut::new(),
+            eof: false,
+        }
+    }
+
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    #[inline]
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    #[inline]
+    pub fn decoder_mut(&mut self) -> &mut D {
+        &mut self.decoder
+    }
+
+    /// Returns the bytes that have been read, but not decoded yet.
+    #[inline]
+    pub fn read_buffer(&self) -> &BytesMut {
+        &self.buffer
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Reader, D: Decoder> FramedRead<R, D>
+where
+    D::Error: From<R::Error>,
+{
+    /// Reads the next frame.
+    ///
+    /// Returns `Ok(None)` once the reader is exhausted and all frames have
+    /// been decoded.
+    pub fn read_frame(&mut self) -> Result<Option<D::Item>, D::Error> {
+        loop {
+            if self.eof {
+                return self.decoder.decode_eof(&mut self.buffer);
+            }
+
+            if let Some(item) = self.decoder.decode(&mut self.buffer)? {
+                return Ok(Some(item));
+            }
+
+            // read directly into the spare capacity of the buffer. the spare capacity is
+            // split off, and joined back afterwards, which doesn't copy.
+            let length = self.buffer.len();
+            self.buffer.reserve_additional(READ_SIZE);
+            let mut spare = self
+                .buffer
+                .split_off(length)
+                .expect("Splitting off the spare capacity failed");
+            let n_read = self.reader.read_into(&mut spare, READ_SIZE);
+            self.buffer
+                .unsplit(spare)
+                .unwrap_or_else(|_| panic!("Joining the spare capacity failed"));
+            if n_read? == 0 {
+                self.eof = true;
+            }
+        }
+    }
+}
+
+impl<R: Reader, D: Decoder> Iterator for FramedRead<R, D>
+where
+    D::Error: From<R::Error>,
+{
+    type Item = Result<D::Item, D::Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_frame().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Decoder,
+        FramedRead,
+    };
+    use crate::{
+        buf::Length,
+        copy,
+        io::End,
+        BytesMut,
+    };
+
+    struct LinesDecoder;
+
+    impl Decoder for LinesDecoder {
+        type Item = String;
+        type Error = End;
+
+        fn decode(&mut self, src: &mut BytesMut) -> Result<Option<String>, End> {
+            let mut data = vec![0; src.len()];
+            copy(&mut data[..], &*src).unwrap();
+
+            let Some(n) = data.iter().position(|b| *b == b'\n')
+            else {
+                return Ok(None);
+            };
+            src.advance(n + 1).unwrap();
+            Ok(Some(String::from_utf8_lossy(&data[..n]).into_owned()))
+        }
+    }
+
+    #[test]
+    fn it_decodes_lines() {
+        let input: &[u8] = b"Hello\nWorld\n\nfoo\n";
+        let lines = FramedRead::new(input, LinesDecoder)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(lines, ["Hello", "World", "", "foo"]);
+    }
+
+    #[test]
+    fn it_fails_on_incomplete_frame() {
+        let input: &[u8] = b"Hello\nWorld";
+        let mut framed = FramedRead::new(input, LinesDecoder);
+        assert_eq!(framed.next().unwrap().unwrap(), "Hello");
+        assert_eq!(
+            framed.next().unwrap().unwrap_err(),
+            End {
+                read: 5,
+                requested: 6,
+                remaining: 0,
+            }
+        );
+        assert_eq!(framed.read_buffer(), b"World");
+    }
+}
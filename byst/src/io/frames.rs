@@ -0,0 +1,83 @@
+use super::BufReader;
+use crate::Bytes;
+
+/// An iterator over successive frames of a reader, whose lengths are
+/// determined from a header.
+///
+/// For each frame, `header_len` bytes are peeked and passed to the length
+/// function, which returns the total length of the frame, including the
+/// header. Each item is the whole frame as [`Bytes`], which is shared without
+/// copying, if the reader's views support it (see
+/// [`BufReader::copy_to_bytes`]).
+///
+/// Iteration stops if fewer than `header_len` bytes remain, if the length
+/// function returns `None` or `0`, or if the remaining bytes only contain a
+/// partial frame. The remaining bytes are left in the reader.
+///
+/// This is created with [`BufReader::frames`].
+#[derive(Debug)]
+pub struct Frames<R, F> {
+    reader: R,
+    header_len: usize,
+    length: F,
+}
+
+impl<R, F> Frames<R, F> {
+    #[inline]
+    pub fn new(reader: R, header_len: usize, length: F) -> Self {
+        Self {
+            reader,
+            header_len,
+            length,
+        }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufReader, F: FnMut(R::View) -> Option<usize>> Iterator for Frames<R, F> {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.reader.peek_view(self.header_len).ok()?;
+        let length = (self.length)(header).filter(|length| *length > 0)?;
+        if length > self.reader.remaining() {
+            return None;
+        }
+        Some(
+            self.reader
+                .copy_to_bytes(length)
+                .expect("Reading a complete frame failed"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::BufReader;
+
+    fn length(header: &[u8]) -> Option<usize> {
+        Some(2 + usize::from(u16::from_be_bytes([header[0], header[1]])))
+    }
+
+    #[test]
+    fn it_yields_complete_frames() {
+        let mut reader: &[u8] = b"\x00\x05Hello\x00\x01 \x00\x05World\x00\x04Hel";
+        let frames = (&mut reader).frames(2, length).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], b"\x00\x05Hello");
+        assert_eq!(frames[1], b"\x00\x01 ");
+        assert_eq!(frames[2], b"\x00\x05World");
+        assert_eq!(reader, b"\x00\x04Hel");
+    }
+
+    #[test]
+    fn it_stops_on_partial_header() {
+        let mut reader: &[u8] = b"\x00";
+        assert_eq!((&mut reader).frames(2, length).count(), 0);
+        assert_eq!(reader, b"\x00");
+    }
+}
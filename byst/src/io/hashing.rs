@@ -0,0 +1,134 @@
+use core::{
+    hash::Hasher,
+    ops::ControlFlow,
+};
+
+use super::{
+    BufWriter,
+    Full,
+    Remaining,
+    Writer,
+};
+use crate::{
+    buf::Buf,
+    util::try_for_each_chunk,
+};
+
+/// A writer that feeds all bytes written through it into a [`Hasher`].
+///
+/// This is useful to compute a checksum or digest of data while writing it
+/// out, in a single pass. Bytes are passed to [`Hasher::write`] chunk by
+/// chunk, and only once they were written to the inner writer. It is created
+/// with [`WriterExt::hashing`](super::WriterExt::hashing).
+///
+/// Bytes skipped with [`Writer::skip`] are not hashed, since their contents
+/// aren't known.
+#[derive(Clone, Debug)]
+pub struct HashingWriter<W, H> {
+    inner: W,
+    hasher: H,
+}
+
+impl<W, H> HashingWriter<W, H> {
+    #[inline]
+    pub fn new(inner: W, hasher: H) -> Self {
+        Self { inner, hasher }
+    }
+
+    #[inline]
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the inner writer and the hasher.
+    #[inline]
+    pub fn finish(self) -> (W, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<W: BufWriter, H: Hasher> Writer for HashingWriter<W, H> {
+    type Error = Full;
+
+    fn write_buf<B: Buf>(&mut self, buf: B) -> Result<(), Full> {
+        let requested = buf.len();
+        let mut written = 0;
+
+        try_for_each_chunk(&mut buf.reader(), |chunk| {
+            if let Err(e) = self.inner.extend(chunk) {
+                self.hasher.write(&chunk[..e.written]);
+                return ControlFlow::Break(Full {
+                    written: written + e.written,
+                    requested,
+                    remaining: e.remaining,
+                });
+            }
+            self.hasher.write(chunk);
+            written += chunk.len();
+            ControlFlow::Continue(())
+        })
+        .map_or(Ok(()), Err)
+    }
+
+    #[inline]
+    fn skip(&mut self, amount: usize) -> Result<(), Full> {
+        self.inner.advance(amount)
+    }
+}
+
+impl<W: BufWriter, H> Remaining for HashingWriter<W, H> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::Hasher,
+    };
+
+    use super::HashingWriter;
+    use crate::{
+        buf::segments::Segments,
+        io::{
+            Full,
+            Remaining,
+            Writer,
+            WriterExt,
+        },
+        BufMut,
+    };
+
+    #[test]
+    fn it_hashes_written_bytes() {
+        let segments: &[&[u8]] = &[b"Hello", b" ", b"World"];
+        let mut output = vec![];
+        let mut output_writer = output.writer();
+        let mut writer = output_writer.hashing(DefaultHasher::new());
+        writer.write_buf(Segments::new(segments)).unwrap();
+        let (_, hasher) = writer.finish();
+
+        let mut expected = DefaultHasher::new();
+        expected.write(b"Hello World");
+        assert_eq!(output, b"Hello World");
+        assert_eq!(hasher.finish(), expected.finish());
+    }
+
+    #[test]
+    fn it_forwards_full() {
+        let mut output = [0u8; 4];
+        let mut writer = HashingWriter::new(&mut output as &mut [u8], DefaultHasher::new());
+        assert_eq!(writer.remaining(), 4);
+
+        let error: Full = writer.write_buf(b"Hello" as &[u8]).unwrap_err();
+        assert_eq!(error.requested, 5);
+        assert!(error.written <= 4);
+
+        let mut expected = DefaultHasher::new();
+        expected.write(&b"Hello"[..error.written]);
+        assert_eq!(writer.hasher().finish(), expected.finish());
+    }
+}
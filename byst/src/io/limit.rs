@@ -54,7 +54,7 @@ impl<R: Reader> Reader for Limit<R> {
         limit: impl Into<Option<usize>>,
     ) -> Result<usize, Self::Error> {
         let limit = if let Some(limit) = limit.into() {
-            std::cmp::min(self.limit, limit)
+            core::cmp::min(self.limit, limit)
         }
         else {
             self.limit
@@ -95,7 +95,7 @@ impl<R: Reader> Reader for Limit<R> {
     }
 
     fn skip(&mut self, amount: usize) -> Result<(), Self::Error> {
-        let amount = std::cmp::min(self.limit, amount);
+        let amount = core::cmp::min(self.limit, amount);
 
         match Reader::skip(&mut self.inner, amount) {
             Ok(()) => {
@@ -120,7 +120,7 @@ impl<R: BufReader> BufReader for Limit<R> {
         }
         else {
             let chunk = self.inner.peek_chunk()?;
-            Some(&chunk[..std::cmp::min(chunk.len(), self.limit)])
+            Some(&chunk[..core::cmp::min(chunk.len(), self.limit)])
         }
     }
 
@@ -190,7 +190,7 @@ impl<R: BufReader> BufReader for Limit<R> {
 
     #[inline]
     fn remaining(&self) -> usize {
-        std::cmp::min(self.limit, self.inner.remaining())
+        core::cmp::min(self.limit, self.inner.remaining())
     }
 }
 
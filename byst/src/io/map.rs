@@ -0,0 +1,317 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use super::{
+    BufReader,
+    BufWriter,
+    End,
+    Full,
+    Reader,
+    Remaining,
+    Seek,
+};
+use crate::{
+    buf::{
+        arc_buf::ArcBufMut,
+        BufExt,
+    },
+    Buf,
+    BufMut,
+    Bytes,
+};
+
+/// Maximum number of bytes that are transformed ahead of the current
+/// position.
+///
+/// This bounds the work done by [`MapBytes::new`] and [`Seek::seek`], which
+/// would otherwise transform the whole chunk of the inner reader every time.
+const WINDOW_SIZE: usize = 256;
+
+/// A reader that transforms each byte read from the inner reader.
+///
+/// This is created with [`BufReader::map_bytes`] or
+/// [`BufReader::map_bytes_mut`].
+///
+/// The transformed bytes have to be stored somewhere, so this breaks
+/// zero-copy by design: up to 256 bytes following the current position are
+/// transformed into an internal buffer, which is returned by
+/// [`BufReader::peek_chunk`], and views are copied into new [`Bytes`].
+///
+/// With any `F: FnMut(u8) -> u8` this is a forward-only [`Reader`], which
+/// passes each byte to `f` exactly once and in order, including skipped bytes.
+/// This works with stateful transformations like a stream cipher. Only with
+/// `F: Fn(u8) -> u8` this is also a [`BufReader`] and [`Seek`], since those
+/// can transform bytes more than once, e.g. when peeking or seeking.
+#[derive(Debug)]
+pub struct MapBytes<R, F> {
+    inner: R,
+    f: F,
+
+    /// The transformed bytes at the start of the current chunk of `inner`.
+    chunk: Vec<u8>,
+
+    /// Offset of the current position in `chunk`.
+    offset: usize,
+}
+
+impl<R: BufReader, F: FnMut(u8) -> u8> MapBytes<R, F> {
+    #[inline]
+    pub fn new(inner: R, f: F) -> Self {
+        let mut this = Self {
+            inner,
+            f,
+            chunk: vec![],
+            offset: 0,
+        };
+        this.fill_chunk();
+        this
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn fill_chunk(&mut self) {
+        self.chunk.clear();
+        self.offset = 0;
+        if let Some(chunk) = self.inner.peek_chunk() {
+            let chunk = &chunk[..chunk.len().min(WINDOW_SIZE)];
+            self.chunk.extend(chunk.iter().map(|byte| (self.f)(*byte)));
+        }
+    }
+
+    /// Updates the transformed chunk after the inner reader was advanced by
+    /// `by` bytes.
+    fn advanced(&mut self, by: usize) {
+        if self.offset + by < self.chunk.len() {
+            self.offset += by;
+        }
+        else {
+            self.fill_chunk();
+        }
+    }
+
+    /// Consumes `n` bytes from the transformed chunk.
+    fn consume(&mut self, n: usize) {
+        debug_assert!(self.offset + n <= self.chunk.len());
+        self.inner
+            .advance(n)
+            .expect("Expected transformed bytes to be in the inner reader");
+        self.advanced(n);
+    }
+}
+
+impl<R: BufReader, F: Fn(u8) -> u8> MapBytes<R, F> {
+    fn map_view(&self, view: impl Buf) -> Bytes {
+        view.bytes_iter().map(&self.f).collect::<ArcBufMut>().into()
+    }
+}
+
+impl<R: BufReader, F: FnMut(u8) -> u8> Reader for MapBytes<R, F> {
+    type Error = End;
+
+    fn read_into<D: BufMut>(
+        &mut self,
+        mut dest: D,
+        limit: impl Into<Option<usize>>,
+    ) -> Result<usize, Self::Error> {
+        let limit = limit.into().unwrap_or(usize::MAX);
+        let mut writer = dest.writer();
+        let mut total_read = 0;
+
+        while total_read < limit {
+            let n = (self.chunk.len() - self.offset).min(limit - total_read);
+            if n == 0 {
+                break;
+            }
+            if let Err(Full { written, .. }) = writer.extend(&self.chunk[self.offset..][..n]) {
+                self.consume(written);
+                total_read += written;
+                break;
+            }
+            self.consume(n);
+            total_read += n;
+        }
+
+        Ok(total_read)
+    }
+
+    fn read_into_exact<D: BufMut>(&mut self, dest: D, length: usize) -> Result<(), Self::Error> {
+        let n_read = self.read_into(dest, length)?;
+        if n_read == length {
+            Ok(())
+        }
+        else {
+            Err(End {
+                read: n_read,
+                requested: length,
+                remaining: self.inner.remaining(),
+            })
+        }
+    }
+
+    fn skip(&mut self, mut amount: usize) -> Result<(), Self::Error> {
+        let remaining = self.inner.remaining();
+        if amount > remaining {
+            return Err(End {
+                read: 0,
+                requested: amount,
+                remaining,
+            });
+        }
+        // skipped bytes still go through `f`, so that a stateful transformation
+        // doesn't get out of sync.
+        while amount > 0 {
+            let n = (self.chunk.len() - self.offset).min(amount);
+            self.consume(n);
+            amount -= n;
+        }
+        Ok(())
+    }
+}
+
+impl<R: BufReader, F> Remaining for MapBytes<R, F> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+}
+
+impl<R: BufReader, F: Fn(u8) -> u8> BufReader for MapBytes<R, F> {
+    type View = Bytes;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        let chunk = &self.chunk[self.offset..];
+        (!chunk.is_empty()).then_some(chunk)
+    }
+
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        let view = self.inner.view(length)?;
+        let view = self.map_view(view);
+        self.advanced(length);
+        Ok(view)
+    }
+
+    #[inline]
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        Ok(self.map_view(self.inner.peek_view(length)?))
+    }
+
+    fn rest(&mut self) -> Self::View {
+        let view = self.inner.rest();
+        let view = self.map_view(view);
+        self.fill_chunk();
+        view
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        self.map_view(self.inner.peek_rest())
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        self.inner.advance(by)?;
+        self.advanced(by);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+}
+
+impl<R: BufReader, F: Fn(u8) -> u8> Seek for MapBytes<R, F> {
+    type Position = R::Position;
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        self.inner.tell()
+    }
+
+    #[inline]
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        let old = self.inner.seek(position);
+        self.fill_chunk();
+        old
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use core::cell::Cell;
+
+    use crate::{
+        io::{
+            BufReader,
+            Reader,
+            ReaderExt,
+            Seek,
+        },
+        Bytes,
+    };
+
+    #[test]
+    fn it_transforms_bytes() {
+        let inner = Bytes::from_static(b"\x00\x0f\xf0\xff\x12");
+        let mut reader = inner.map_bytes(|byte| byte ^ 0xff);
+        assert_eq!(reader.peek_chunk().unwrap(), b"\xff\xf0\x0f\x00\xed");
+        assert_eq!(reader.read::<u8>().unwrap(), 0xff);
+        assert_eq!(reader.peek_chunk().unwrap(), b"\xf0\x0f\x00\xed");
+
+        let start = reader.tell();
+        assert_eq!(reader.view(2).unwrap(), b"\xf0\x0f");
+        assert_eq!(reader.rest(), b"\x00\xed");
+        assert!(reader.peek_chunk().is_none());
+
+        reader.seek(&start);
+        assert_eq!(reader.read_byte_array::<4>().unwrap(), *b"\xf0\x0f\x00\xed");
+    }
+
+    #[test]
+    fn it_transforms_each_byte_once_with_stateful_closure() {
+        let mut key = 0u8;
+        let mut reader = Bytes::from_static(&[0; 600]).map_bytes_mut(|byte| {
+            key = key.wrapping_add(1);
+            byte ^ key
+        });
+
+        assert_eq!(reader.read_byte_array::<3>().unwrap(), [1, 2, 3]);
+        reader.skip(300).unwrap();
+        assert_eq!(reader.read::<u8>().unwrap(), 48);
+        let mut rest = vec![];
+        assert_eq!(reader.read_into(&mut rest, None).unwrap(), 296);
+        assert_eq!(rest[0], 49);
+        assert!(reader.read::<u8>().is_err());
+    }
+
+    #[test]
+    fn seeking_transforms_a_bounded_window() {
+        let n_transformed = Cell::new(0);
+        let mut reader = Bytes::from(vec![0; 4096]).map_bytes(|byte| {
+            n_transformed.set(n_transformed.get() + 1);
+            byte
+        });
+        let start = reader.tell();
+        reader.read::<u8>().unwrap();
+
+        n_transformed.set(0);
+        reader.seek(&start);
+        assert!(n_transformed.get() <= 256);
+        assert_eq!(reader.remaining(), 4096);
+    }
+
+    #[test]
+    fn it_transforms_chained_readers() {
+        let inner = (b"Hel" as &[u8]).chain(b"lo" as &[u8]);
+        let mut reader = inner.map_bytes(|byte| byte.to_ascii_uppercase());
+        assert_eq!(reader.read_byte_array::<5>().unwrap(), *b"HELLO");
+        assert_eq!(reader.remaining(), 0);
+    }
+}
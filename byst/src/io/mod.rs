@@ -1,6 +1,15 @@
+mod buffered;
+mod chain;
+mod chunks;
 mod count;
+mod decoder;
+mod frames;
+mod hashing;
 mod limit;
+mod map;
 mod read;
+mod records;
+mod tee;
 mod write;
 
 pub use byst_macros::{
@@ -9,18 +18,35 @@ pub use byst_macros::{
 };
 
 pub use self::{
+    buffered::BufferedWriter,
+    chain::{
+        ChainReader,
+        ChainView,
+    },
+    chunks::Chunks,
     count::Count,
+    decoder::{
+        Decoder,
+        FramedRead,
+    },
+    frames::Frames,
+    hashing::HashingWriter,
     limit::Limit,
+    map::MapBytes,
     read::{
         read,
         BufReader,
         End,
+        InvalidChar,
         InvalidDiscriminant,
         Read,
         ReadError,
+        ReadValueError,
         Reader,
         ReaderExt,
     },
+    records::Records,
+    tee::Tee,
     write::{
         BufWriter,
         Full,
@@ -63,7 +89,7 @@ impl<'a> Seek for &'a [u8] {
 
     #[inline]
     fn seek(&mut self, position: &Self::Position) -> Self::Position {
-        std::mem::replace(self, *position)
+        core::mem::replace(self, *position)
     }
 }
 
@@ -1,22 +1,44 @@
-use std::{
+use alloc::string::String;
+use core::{
     convert::Infallible,
     marker::PhantomData,
     net::{
         Ipv4Addr,
         Ipv6Addr,
+        SocketAddrV4,
+        SocketAddrV6,
     },
+    ops::ControlFlow,
+    str::Utf8Error,
 };
+#[cfg(feature = "std")]
+use std::io::IoSlice;
 
 use byst_macros::for_tuple;
 
 use super::{
+    ChainReader,
+    Chunks,
+    Count,
+    Frames,
     Limit,
+    MapBytes,
+    Records,
     Seek,
+    Tee,
 };
 use crate::{
+    buf::Length,
+    endianness::{
+        Endianness,
+        NetworkEndian,
+    },
     impl_me,
+    util::try_for_each_chunk,
     Buf,
     BufMut,
+    Bytes,
+    BytesMut,
 };
 
 /// Something that can be read from a reader `R`, given the context `C`.
@@ -72,6 +94,18 @@ pub trait ReaderExt: Reader {
         T::read(self, context)
     }
 
+    /// Wraps this reader in a [`Count`], which counts the number of bytes read
+    /// from it.
+    ///
+    /// This is useful to report the offset at which an error occurred.
+    #[inline]
+    fn counted(self) -> Count<Self>
+    where
+        Self: Sized,
+    {
+        Count::new(self)
+    }
+
     #[inline]
     fn read_byte_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Error> {
         let mut buf = [0u8; N];
@@ -79,6 +113,14 @@ pub trait ReaderExt: Reader {
         Ok(buf)
     }
 
+    /// Reads exactly `N` bytes into an array.
+    ///
+    /// This is the same as [`read_byte_array`][Self::read_byte_array].
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Error> {
+        self.read_byte_array()
+    }
+
     #[inline]
     fn limit(&mut self, limit: usize) -> Limit<&mut Self> {
         Limit::new(self, limit)
@@ -122,6 +164,429 @@ pub trait BufReader: Reader<Error = End> + Seek {
 
     /// Returns the number of bytes remaining.
     fn remaining(&self) -> usize;
+
+    /// Chains this reader with `next`. The returned reader will first read
+    /// from `self` until it's exhausted, and then from `next`.
+    #[inline]
+    fn chain<R: BufReader>(self, next: R) -> ChainReader<Self, R>
+    where
+        Self: Sized,
+    {
+        ChainReader::new(self, next)
+    }
+
+    /// Transforms each byte read from this reader with `f`.
+    ///
+    /// This copies the bytes, see [`MapBytes`].
+    #[inline]
+    fn map_bytes<F: Fn(u8) -> u8>(self, f: F) -> MapBytes<Self, F>
+    where
+        Self: Sized,
+    {
+        MapBytes::new(self, f)
+    }
+
+    /// Transforms each byte read from this reader with a stateful `f`.
+    ///
+    /// Unlike with [`map_bytes`](Self::map_bytes), the returned reader is only
+    /// a forward-only [`Reader`], which passes each byte to `f` exactly once.
+    /// This is a separate method, because a closure passed where `FnMut` is
+    /// expected only implements `FnMut`, even if it doesn't mutate anything.
+    #[inline]
+    fn map_bytes_mut<F: FnMut(u8) -> u8>(self, f: F) -> MapBytes<Self, F>
+    where
+        Self: Sized,
+    {
+        MapBytes::new(self, f)
+    }
+
+    /// Fills `dst` with the chunks starting at the current position, for use
+    /// with [`write_vectored`](std::io::Write::write_vectored).
+    ///
+    /// Returns the number of [`IoSlice`]s written to `dst`. This doesn't
+    /// advance the cursor. The default implementation only fills in the chunk
+    /// returned by [`peek_chunk`](Self::peek_chunk).
+    #[cfg(feature = "std")]
+    #[inline]
+    fn peek_io_slices<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        match (dst.first_mut(), self.peek_chunk()) {
+            (Some(slot), Some(chunk)) => {
+                *slot = IoSlice::new(chunk);
+                1
+            }
+            _ => 0,
+        }
+    }
+
+    /// Wraps this reader in a [`Tee`], which records all bytes consumed from it
+    /// into `sink`.
+    ///
+    /// This is useful to retain the exact raw bytes that were parsed, e.g. to
+    /// hash or forward them.
+    #[inline]
+    fn tee(self, sink: &mut BytesMut) -> Tee<'_, Self>
+    where
+        Self: Sized,
+    {
+        Tee::new(self, sink)
+    }
+
+    /// Returns an iterator over successive records of `size` bytes.
+    ///
+    /// The iterator stops once fewer than `size` bytes remain, and leaves
+    /// them in the reader. If `size` is 0, the iterator is empty.
+    #[inline]
+    fn records(&mut self, size: usize) -> Records<&mut Self> {
+        Records::new(self, size)
+    }
+
+    /// Returns an iterator over frames whose lengths are computed from a
+    /// header.
+    ///
+    /// For each frame, `header_len` bytes are peeked and passed to `length`,
+    /// which returns the total length of the frame including the header, or
+    /// `None` if it can't be determined yet. The iterator stops at the first
+    /// partial frame, see [`Frames`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use byst::io::BufReader;
+    /// let mut reader: &[u8] = b"\x02ab\x03cde\x04f";
+    /// let frames = (&mut reader)
+    ///     .frames(1, |header: &[u8]| Some(1 + usize::from(header[0])))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(frames, [b"\x02ab" as &[u8], b"\x03cde"]);
+    /// assert_eq!(reader, b"\x04f");
+    /// ```
+    #[inline]
+    fn frames<F>(self, header_len: usize, length: F) -> Frames<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::View) -> Option<usize>,
+    {
+        Frames::new(self, header_len, length)
+    }
+
+    /// Returns an iterator over the contiguous chunks of this reader.
+    ///
+    /// This doesn't advance `self`, but iterates over a clone of it.
+    #[inline]
+    fn chunks(&self) -> Chunks<Self>
+    where
+        Self: Clone,
+    {
+        Chunks::new(self.clone())
+    }
+
+    /// Returns the next byte without advancing the cursor.
+    #[inline]
+    fn peek_u8(&self) -> Result<u8, End> {
+        self.peek_chunk()
+            .and_then(|chunk| chunk.first().copied())
+            .ok_or(End {
+                read: 0,
+                requested: 1,
+                remaining: 0,
+            })
+    }
+
+    /// Reads a `T` without advancing the cursor.
+    ///
+    /// This reads from a clone of the reader.
+    #[inline]
+    fn peek<T: Read<Self, ()>>(&self) -> Result<T, T::Error>
+    where
+        Self: Clone,
+    {
+        self.peek_with(())
+    }
+
+    /// Reads a `T` with the given context without advancing the cursor.
+    ///
+    /// This reads from a clone of the reader.
+    #[inline]
+    fn peek_with<T: Read<Self, C>, C>(&self, context: C) -> Result<T, T::Error>
+    where
+        Self: Clone,
+    {
+        T::read(&mut self.clone(), context)
+    }
+
+    /// Returns an independent reader at the same position.
+    ///
+    /// The fork shares the underlying buffer with `self`, so for readers like
+    /// [`Bytes`] this only bumps a reference count. Advancing one reader
+    /// doesn't affect the other, which is useful for speculative parsing.
+    #[inline]
+    fn fork(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.clone()
+    }
+
+    /// Returns whether at least `n` bytes are left to read.
+    #[inline]
+    fn has_remaining(&self, n: usize) -> bool {
+        self.remaining() >= n
+    }
+
+    /// Reads a `T` with the given context, if there are enough bytes left.
+    ///
+    /// If reading fails, `None` is returned and the cursor isn't advanced (see
+    /// [`checkpoint`](Self::checkpoint)).
+    #[inline]
+    fn try_read<T: Read<Self, C, Error = End>, C>(&mut self, context: C) -> Option<T> {
+        self.checkpoint(|reader| T::read(reader, context)).ok()
+    }
+
+    /// Advances the cursor past all leading bytes for which `predicate`
+    /// returns `true`.
+    ///
+    /// Returns the number of bytes skipped. This stops at the first byte that
+    /// doesn't match, or at the end of the reader.
+    fn skip_while(&mut self, mut predicate: impl FnMut(u8) -> bool) -> usize {
+        let mut skipped = 0;
+        let rest = try_for_each_chunk(self, |chunk| {
+            match chunk.iter().position(|byte| !predicate(*byte)) {
+                Some(i) => ControlFlow::Break(i),
+                None => {
+                    skipped += chunk.len();
+                    ControlFlow::Continue(())
+                }
+            }
+        });
+        if let Some(n) = rest {
+            self.advance(n)
+                .expect("Advancing within the current chunk failed");
+            skipped += n;
+        }
+        skipped
+    }
+
+    /// Reads all leading bytes for which `predicate` returns `true`.
+    ///
+    /// This is like [`skip_while`](Self::skip_while), but returns a view of
+    /// the matched bytes.
+    fn take_while(&mut self, predicate: impl FnMut(u8) -> bool) -> Self::View {
+        let start = self.tell();
+        let length = self.skip_while(predicate);
+        self.seek(&start);
+        self.view(length).expect("Reading the skipped bytes failed")
+    }
+
+    /// Runs `f` on the reader, and rewinds the reader if `f` fails.
+    ///
+    /// The position is recorded with [`tell`](Seek::tell) before calling `f`.
+    /// If `f` returns an error, the reader is restored to that position with
+    /// [`seek`](Seek::seek) before the error is returned. This can be used to
+    /// backtrack when trying alternative parsers.
+    #[inline]
+    fn checkpoint<T, E>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, E>) -> Result<T, E> {
+        let start = self.tell();
+        let result = f(self);
+        if result.is_err() {
+            self.seek(&start);
+        }
+        result
+    }
+
+    /// Reads `length` bytes and returns them as owned [`Bytes`].
+    ///
+    /// Unlike [`view`](Self::view), this always returns [`Bytes`]. The bytes
+    /// are shared without copying, if the reader's views support it (see
+    /// [`Buf::to_bytes`]), e.g. for [`Bytes`] or
+    /// [`ArcBuf`](crate::buf::arc_buf::ArcBuf). Otherwise they're copied.
+    #[inline]
+    fn copy_to_bytes(&mut self, length: usize) -> Result<Bytes, End> {
+        Ok(self.view(length)?.to_bytes())
+    }
+
+    /// Reads a length of type `L` in endianness `E`, followed by that many
+    /// bytes.
+    ///
+    /// The bytes are returned without copying, if the reader's views support
+    /// it (see [`Buf::to_bytes`]). If the payload is truncated, this fails with
+    /// [`End`] and doesn't advance the cursor. A length that doesn't fit into a
+    /// `usize` is treated as truncation.
+    fn read_length_prefixed<L, E>(&mut self) -> Result<Bytes, End>
+    where
+        L: Read<Self, E, Error = End> + TryInto<usize>,
+        E: Endianness + Default,
+    {
+        let start = self.tell();
+        let length = L::read(self, E::default())?;
+
+        let Ok(length) = length.try_into()
+        else {
+            let remaining = self.remaining();
+            self.seek(&start);
+            return Err(End {
+                read: 0,
+                requested: usize::MAX,
+                remaining,
+            });
+        };
+
+        if length == 0 {
+            return Ok(Bytes::new());
+        }
+
+        let result = self.copy_to_bytes(length);
+        if result.is_err() {
+            self.seek(&start);
+        }
+        result
+    }
+
+    /// Reads up to and including the first occurrence of `delimiter`.
+    ///
+    /// This scans through all chunks of the reader. If `delimiter` doesn't
+    /// occur, this fails with [`End`] and doesn't advance the cursor. Use
+    /// [`rest`](Self::rest) to get the remaining bytes in this case.
+    fn read_until(&mut self, delimiter: u8) -> Result<Self::View, End> {
+        let start = self.tell();
+        let mut length = 0;
+        let found = try_for_each_chunk(self, |chunk| {
+            match chunk.iter().position(|byte| *byte == delimiter) {
+                Some(i) => ControlFlow::Break(i),
+                None => {
+                    length += chunk.len();
+                    ControlFlow::Continue(())
+                }
+            }
+        });
+        self.seek(&start);
+
+        if let Some(i) = found {
+            self.view(length + i + 1)
+        }
+        else {
+            Err(End {
+                read: 0,
+                requested: length + 1,
+                remaining: length,
+            })
+        }
+    }
+
+    /// Reads a NUL-terminated string.
+    ///
+    /// This returns the bytes before the first `0x00` and advances past it.
+    /// The terminator isn't included in the returned bytes. A NUL at the
+    /// current position yields an empty [`Bytes`]. If there is no NUL, this
+    /// fails with [`End`] and doesn't advance the cursor.
+    ///
+    /// The bytes are returned without copying, if the reader's views support
+    /// it (see [`Buf::to_bytes`]).
+    fn read_cstr(&mut self) -> Result<Bytes, End> {
+        let view = self.read_until(0)?;
+        let mut bytes = view.to_bytes();
+        bytes.truncate(bytes.len() - 1);
+        Ok(bytes)
+    }
+
+    /// Reads a NUL-terminated UTF-8 string.
+    ///
+    /// This is like [`read_cstr`](Self::read_cstr), but also copies the
+    /// string into a [`String`]. If it isn't valid UTF-8, this fails and
+    /// doesn't advance the cursor.
+    fn read_cstr_str(&mut self) -> Result<String, ReadValueError<End, Utf8Error>> {
+        let start = self.tell();
+        let bytes = self.read_cstr()?;
+        String::try_from(bytes).map_err(|e| {
+            self.seek(&start);
+            ReadValueError::Invalid {
+                value: e.utf8_error(),
+                read: 0,
+            }
+        })
+    }
+
+    /// Reads a discriminant of type `D` in endianness `E`, and maps it to a
+    /// `T` with `map`.
+    ///
+    /// This is useful for enums that are encoded as small integers. If `map`
+    /// returns `None`, this fails with [`ReadValueError::Invalid`], which
+    /// carries the unrecognized discriminant, and doesn't advance the cursor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use byst::{endianness::BigEndian, io::{BufReader, InvalidDiscriminant, ReadValueError}};
+    /// #[derive(Debug, PartialEq)]
+    /// enum Kind {
+    ///     Request,
+    ///     Response,
+    /// }
+    ///
+    /// fn kind(tag: u8) -> Option<Kind> {
+    ///     match tag {
+    ///         1 => Some(Kind::Request),
+    ///         2 => Some(Kind::Response),
+    ///         _ => None,
+    ///     }
+    /// }
+    ///
+    /// let mut reader: &[u8] = b"\x02\x03";
+    /// assert_eq!(
+    ///     reader.read_tag::<u8, BigEndian, _>(kind),
+    ///     Ok(Kind::Response)
+    /// );
+    /// assert_eq!(
+    ///     reader.read_tag::<u8, BigEndian, _>(kind),
+    ///     Err(ReadValueError::Invalid {
+    ///         value: InvalidDiscriminant(3),
+    ///         read: 0
+    ///     })
+    /// );
+    /// ```
+    fn read_tag<D, E, T>(
+        &mut self,
+        map: impl FnOnce(D) -> Option<T>,
+    ) -> Result<T, ReadValueError<End, InvalidDiscriminant<D>>>
+    where
+        D: Read<Self, E, Error = End> + Copy,
+        E: Endianness + Default,
+    {
+        let start = self.tell();
+        let tag = D::read(self, E::default())?;
+        map(tag).ok_or_else(|| {
+            self.seek(&start);
+            ReadValueError::Invalid {
+                value: InvalidDiscriminant(tag),
+                read: 0,
+            }
+        })
+    }
+
+    /// Skips padding up to the next multiple of `n` bytes.
+    ///
+    /// `stream_start_offset` is the reader's current offset relative to the
+    /// start of the stream, e.g. as tracked by [`Count`]. The padding bytes
+    /// aren't validated. If there aren't enough bytes left, this fails with
+    /// [`End`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use byst::io::{BufReader, ReaderExt};
+    /// let mut reader = (b"\x01\x02\x03\x04\x05\x00\x00\x00\x06" as &[u8]).counted();
+    /// BufReader::advance(&mut reader, 5).unwrap();
+    /// reader.align_to(4, reader.count()).unwrap();
+    /// assert_eq!(reader.count(), 8);
+    /// ```
+    #[inline]
+    fn align_to(&mut self, n: usize, stream_start_offset: usize) -> Result<(), End> {
+        assert!(n.is_power_of_two(), "Alignment must be a power of two: {n}");
+        let padding = stream_start_offset.wrapping_neg() & (n - 1);
+        self.advance(padding)
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, thiserror::Error)]
@@ -149,6 +614,7 @@ impl ReadError for End {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<End> for std::io::ErrorKind {
     #[inline]
     fn from(_: End) -> Self {
@@ -156,6 +622,7 @@ impl From<End> for std::io::ErrorKind {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<End> for std::io::Error {
     #[inline]
     fn from(_: End) -> Self {
@@ -174,6 +641,43 @@ impl From<Infallible> for End {
 #[error("Invalid discriminant: {0}")]
 pub struct InvalidDiscriminant<D>(pub D);
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid char: {0:#x}")]
+pub struct InvalidChar(pub u32);
+
+/// Error returned when reading a value that can be invalid, e.g. a [`char`].
+///
+/// Either reading failed with `E`, or the value was read, but is invalid.
+/// `V` describes why, e.g. [`InvalidChar`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ReadValueError<E, V> {
+    #[error("{0}")]
+    Read(#[from] E),
+    /// The value is invalid. `read` is the number of bytes that were read.
+    #[error("{value}")]
+    Invalid { value: V, read: usize },
+}
+
+impl<E: ReadError, V> ReadError for ReadValueError<E, V> {
+    #[inline]
+    fn from_end(end: End) -> Self {
+        Self::Read(E::from_end(end))
+    }
+
+    #[inline]
+    fn is_end(&self) -> bool {
+        matches!(self, Self::Read(e) if e.is_end())
+    }
+
+    #[inline]
+    fn amount_read(&self) -> usize {
+        match self {
+            Self::Read(e) => e.amount_read(),
+            Self::Invalid { read, .. } => *read,
+        }
+    }
+}
+
 impl<'a, R: Reader> Reader for &'a mut R {
     type Error = <R as Reader>::Error;
 
@@ -210,6 +714,12 @@ impl<'a, R: BufReader> BufReader for &'a mut R {
         R::peek_chunk(*self)
     }
 
+    #[cfg(feature = "std")]
+    #[inline]
+    fn peek_io_slices<'b>(&'b self, dst: &mut [IoSlice<'b>]) -> usize {
+        R::peek_io_slices(*self, dst)
+    }
+
     #[inline]
     fn view(&mut self, length: usize) -> Result<Self::View, End> {
         R::view(*self, length)
@@ -286,7 +796,7 @@ impl<'a> BufReader for &'a [u8] {
 
     #[inline]
     fn rest(&mut self) -> Self::View {
-        std::mem::take(self)
+        core::mem::take(self)
     }
 
     #[inline]
@@ -332,16 +842,18 @@ impl<R, T> Read<R, ()> for PhantomData<T> {
         Ok(PhantomData)
     }
 }
-/*
-impl<R: Reader, C, T: Read<R, C>, const N: usize> Read<R, C> for [T; N] {
-    type Error = End;
+/// Reads `N` elements in sequence, with the same endianness.
+///
+/// This is only implemented for endianness contexts, since it would conflict
+/// with the implementation for byte arrays otherwise.
+impl<R, E: Endianness + Copy, T: Read<R, E>, const N: usize> Read<R, E> for [T; N] {
+    type Error = T::Error;
 
     #[inline]
-    fn read(reader: &mut R, _context: C) -> Result<Self, Self::Error> {
-        todo!();
+    fn read(reader: &mut R, context: E) -> Result<Self, Self::Error> {
+        core::array::try_from_fn(|_| T::read(reader, context))
     }
 }
-*/
 
 impl<R: Reader, const N: usize> Read<R, ()> for [u8; N] {
     type Error = <R as Reader>::Error;
@@ -370,6 +882,37 @@ impl<R: Reader> Read<R, ()> for i8 {
     }
 }
 
+/// Single bytes have no byte order, so they can be read with any endianness.
+///
+/// This lets them be used in structs deriving [`Read`] with a default
+/// endianness.
+impl<R: Reader, E: Endianness> Read<R, E> for u8 {
+    type Error = <R as Reader>::Error;
+
+    #[inline]
+    fn read(reader: &mut R, _context: E) -> Result<Self, Self::Error> {
+        reader.read::<u8>()
+    }
+}
+
+impl<R: Reader, E: Endianness> Read<R, E> for i8 {
+    type Error = <R as Reader>::Error;
+
+    #[inline]
+    fn read(reader: &mut R, _context: E) -> Result<Self, Self::Error> {
+        reader.read::<i8>()
+    }
+}
+
+impl<R: Reader> Read<R, ()> for bool {
+    type Error = <R as Reader>::Error;
+
+    #[inline]
+    fn read(reader: &mut R, _context: ()) -> Result<Self, Self::Error> {
+        Ok(reader.read::<u8>()? != 0)
+    }
+}
+
 impl<R: Reader> Read<R, ()> for Ipv4Addr {
     type Error = <R as Reader>::Error;
 
@@ -388,6 +931,32 @@ impl<R: Reader> Read<R, ()> for Ipv6Addr {
     }
 }
 
+/// Reads the address followed by the port, both in network byte order.
+impl<R: Reader> Read<R, ()> for SocketAddrV4 {
+    type Error = <R as Reader>::Error;
+
+    #[inline]
+    fn read(reader: &mut R, _context: ()) -> Result<Self, Self::Error> {
+        let ip = reader.read::<Ipv4Addr>()?;
+        let port = reader.read_with::<u16, _>(NetworkEndian)?;
+        Ok(SocketAddrV4::new(ip, port))
+    }
+}
+
+/// Reads the address followed by the port, both in network byte order.
+///
+/// The flow info and scope ID are not part of the encoding, and are set to 0.
+impl<R: Reader> Read<R, ()> for SocketAddrV6 {
+    type Error = <R as Reader>::Error;
+
+    #[inline]
+    fn read(reader: &mut R, _context: ()) -> Result<Self, Self::Error> {
+        let ip = reader.read::<Ipv6Addr>()?;
+        let port = reader.read_with::<u16, _>(NetworkEndian)?;
+        Ok(SocketAddrV6::new(ip, port, 0, 0))
+    }
+}
+
 /// Implements [`Read`] for tuples.
 ///
 /// # TODO
@@ -454,14 +1023,33 @@ pub use read;
 
 #[cfg(test)]
 mod tests {
-    use std::marker::PhantomData;
-
-    use crate::io::{
-        read,
-        End,
-        InvalidDiscriminant,
-        Read,
-        ReaderExt,
+    use std::{
+        marker::PhantomData,
+        net::{
+            Ipv4Addr,
+            Ipv6Addr,
+            SocketAddrV4,
+            SocketAddrV6,
+        },
+    };
+
+    use crate::{
+        buf::arc_buf::ArcBufMut,
+        endianness::{
+            BigEndian,
+            LittleEndian,
+        },
+        io::{
+            read,
+            BufReader,
+            End,
+            InvalidDiscriminant,
+            Read,
+            ReadError,
+            ReadValueError,
+            ReaderExt,
+        },
+        Bytes,
     };
 
     macro_rules! assert_derive_read {
@@ -500,6 +1088,304 @@ mod tests {
         };
     }
 
+    #[test]
+    fn read_until_finds_delimiter() {
+        let mut reader: &'static [u8] = b"Hello\nWorld\n";
+        assert_eq!(reader.read_until(b'\n').unwrap(), b"Hello\n");
+        assert_eq!(reader.read_until(b'\n').unwrap(), b"World\n");
+        assert!(reader.is_empty());
+        assert!(reader.read_until(b'\n').unwrap_err().is_end());
+    }
+
+    #[test]
+    fn read_until_scans_all_chunks() {
+        let mut reader = (b"Hel" as &[u8]).chain(b"lo\nWorld" as &[u8]);
+        assert_eq!(reader.read_until(b'\n').unwrap(), b"Hello\n");
+        assert_eq!(
+            reader.read_until(b'\n').unwrap_err(),
+            End {
+                read: 0,
+                requested: 6,
+                remaining: 5,
+            }
+        );
+        assert_eq!(reader.rest(), b"World");
+    }
+
+    #[test]
+    fn read_cstr_strips_terminator() {
+        let mut reader = (b"Hel" as &[u8]).chain(b"lo\0\0World" as &[u8]);
+        assert_eq!(reader.read_cstr().unwrap(), b"Hello");
+        assert_eq!(reader.read_cstr().unwrap(), b"");
+        assert_eq!(
+            reader.read_cstr().unwrap_err(),
+            End {
+                read: 0,
+                requested: 6,
+                remaining: 5,
+            }
+        );
+        assert_eq!(reader.rest(), b"World");
+    }
+
+    #[test]
+    fn read_cstr_str_validates_utf8() {
+        let mut reader: &'static [u8] = b"Hello\0\xff\0";
+        assert_eq!(reader.read_cstr_str().unwrap(), "Hello");
+        assert!(matches!(
+            reader.read_cstr_str().unwrap_err(),
+            ReadValueError::Invalid { read: 0, .. }
+        ));
+        assert_eq!(reader, b"\xff\0");
+    }
+
+    #[test]
+    fn peek_doesnt_advance_reader() {
+        let mut reader: &'static [u8] = b"\x12\x34\x56";
+        assert_eq!(reader.peek_u8().unwrap(), 0x12);
+        assert_eq!(reader.peek_with::<u16, _>(BigEndian).unwrap(), 0x1234);
+        assert_eq!(reader.peek_with::<u16, _>(LittleEndian).unwrap(), 0x3412);
+        assert_eq!(reader.remaining(), 3);
+        assert_eq!(reader.read_with::<u16, _>(BigEndian).unwrap(), 0x1234);
+        assert_eq!(reader.peek::<u8>().unwrap(), 0x56);
+        assert_eq!(reader.read::<u8>().unwrap(), 0x56);
+        assert!(reader.peek_u8().unwrap_err().is_end());
+    }
+
+    #[test]
+    fn copy_to_bytes_shares_storage() {
+        let mut reader = Bytes::from(b"Hello World".iter().copied().collect::<ArcBufMut>());
+        let start = reader.peek_chunk().unwrap().as_ptr();
+
+        let hello = reader.copy_to_bytes(5).unwrap();
+        assert_eq!(hello, b"Hello");
+        assert_eq!(hello.peek_chunk().unwrap().as_ptr(), start);
+        assert_eq!(reader.ref_count().ref_count(), Some(2));
+        assert_eq!(reader, b" World");
+
+        assert!(reader.copy_to_bytes(7).unwrap_err().is_end());
+        assert_eq!(reader, b" World");
+    }
+
+    #[test]
+    fn read_tag_maps_discriminants() {
+        #[derive(Debug, PartialEq)]
+        enum Variant {
+            A,
+            B,
+        }
+
+        fn variant(tag: u8) -> Option<Variant> {
+            match tag {
+                1 => Some(Variant::A),
+                2 => Some(Variant::B),
+                _ => None,
+            }
+        }
+
+        let mut reader: &'static [u8] = b"\x01\x09";
+        assert_eq!(
+            reader.read_tag::<u8, BigEndian, _>(variant).unwrap(),
+            Variant::A
+        );
+        assert_eq!(
+            reader.read_tag::<u8, BigEndian, _>(variant).unwrap_err(),
+            ReadValueError::Invalid {
+                value: InvalidDiscriminant(9),
+                read: 0,
+            }
+        );
+        assert_eq!(reader, b"\x09");
+
+        let mut reader: &'static [u8] = b"\x00\x02\x00";
+        assert_eq!(
+            reader
+                .read_tag::<u16, BigEndian, _>(|tag| (tag == 2).then_some(Variant::B))
+                .unwrap(),
+            Variant::B
+        );
+        assert!(matches!(
+            reader.read_tag::<u16, BigEndian, _>(|_| Some(Variant::A)),
+            Err(ReadValueError::Read(_))
+        ));
+    }
+
+    #[test]
+    fn read_length_prefixed_reads_payload() {
+        let mut reader: &'static [u8] = b"\x00\x05Hello\x00\x00\x05\x00\x00\x00World!";
+        assert_eq!(
+            reader.read_length_prefixed::<u16, BigEndian>().unwrap(),
+            b"Hello"
+        );
+        assert_eq!(
+            reader.read_length_prefixed::<u16, BigEndian>().unwrap(),
+            b""
+        );
+        assert_eq!(
+            reader.read_length_prefixed::<u32, LittleEndian>().unwrap(),
+            b"World"
+        );
+        assert_eq!(reader, b"!");
+    }
+
+    #[test]
+    fn read_length_prefixed_fails_on_truncation() {
+        let mut reader: &'static [u8] = b"\x00\x00\x00\x06Hello";
+        assert_eq!(
+            reader.read_length_prefixed::<u32, BigEndian>().unwrap_err(),
+            End {
+                read: 0,
+                requested: 6,
+                remaining: 5,
+            }
+        );
+        assert_eq!(reader.len(), 9);
+
+        let mut reader: &'static [u8] = b"\x00";
+        assert!(reader
+            .read_length_prefixed::<u16, BigEndian>()
+            .unwrap_err()
+            .is_end());
+    }
+
+    #[test]
+    fn it_reads_ip_addresses() {
+        let mut reader: &'static [u8] = b"\xc0\xa8\x00\x01\xc0\xa8\x00\x01\x1f\x90";
+        assert_eq!(
+            reader.read::<Ipv4Addr>().unwrap(),
+            Ipv4Addr::new(192, 168, 0, 1)
+        );
+        assert_eq!(
+            reader.read::<SocketAddrV4>().unwrap(),
+            SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 8080)
+        );
+        assert!(reader.is_empty());
+
+        let mut reader: &'static [u8] =
+            b"\x20\x01\x0d\xb8\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x00\x50";
+        assert_eq!(
+            reader.read::<SocketAddrV6>().unwrap(),
+            SocketAddrV6::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 80, 0, 0)
+        );
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn forked_readers_advance_independently() {
+        let mut reader = Bytes::from_static(b"Hello World");
+        let mut fork = reader.fork();
+        assert_eq!(BufReader::view(&mut fork, 6).unwrap(), b"Hello ");
+        assert_eq!(fork, b"World");
+        assert_eq!(reader, b"Hello World");
+        assert_eq!(BufReader::view(&mut reader, 5).unwrap(), b"Hello");
+        assert_eq!(reader, b" World");
+        assert_eq!(fork, b"World");
+    }
+
+    #[test]
+    fn checkpoint_rewinds_on_error() {
+        let mut reader: &'static [u8] = b"\x12\x34\x56";
+        assert!(reader
+            .checkpoint(|reader| {
+                reader.read_with::<u16, _>(BigEndian)?;
+                reader.read_with::<u16, _>(BigEndian)
+            })
+            .unwrap_err()
+            .is_end());
+        assert_eq!(reader, b"\x12\x34\x56");
+
+        let value = reader
+            .checkpoint(|reader| reader.read_with::<u16, _>(BigEndian))
+            .unwrap();
+        assert_eq!(value, 0x1234);
+        assert_eq!(reader, b"\x56");
+    }
+
+    #[test]
+    fn align_to_skips_padding() {
+        let mut reader = (b"\x01\x02\x03\x04\x05\x00\x00\x00\x06\x07" as &[u8]).counted();
+        BufReader::advance(&mut reader, 5).unwrap();
+        reader.align_to(4, reader.count()).unwrap();
+        assert_eq!(reader.count(), 8);
+        reader.align_to(4, reader.count()).unwrap();
+        assert_eq!(reader.count(), 8);
+        reader.align_to(1, reader.count()).unwrap();
+        assert_eq!(reader.read::<u8>().unwrap(), 0x06);
+        assert!(reader.align_to(8, reader.count()).is_err());
+    }
+
+    #[test]
+    fn has_remaining_checks_boundary() {
+        let reader: &'static [u8] = b"\x12\x34\x56";
+        assert!(reader.has_remaining(0));
+        assert!(reader.has_remaining(3));
+        assert!(!reader.has_remaining(4));
+    }
+
+    #[test]
+    fn try_read_returns_none_when_short() {
+        let mut reader: &'static [u8] = b"\x12\x34\x56";
+        assert_eq!(reader.try_read::<u32, _>(BigEndian), None);
+        assert_eq!(reader, b"\x12\x34\x56");
+        assert_eq!(reader.try_read::<u16, _>(BigEndian), Some(0x1234));
+        assert_eq!(reader.try_read::<u16, _>(BigEndian), None);
+        assert_eq!(reader, b"\x56");
+    }
+
+    #[test]
+    fn skip_while_stops_at_first_mismatch() {
+        let mut reader: &'static [u8] = b"   foo bar";
+        assert_eq!(reader.skip_while(|byte| byte == b' '), 3);
+        assert_eq!(reader, b"foo bar");
+        assert_eq!(reader.skip_while(|byte| byte == b' '), 0);
+        assert_eq!(reader.take_while(|byte| byte != b' '), b"foo");
+        assert_eq!(reader, b" bar");
+        assert_eq!(reader.skip_while(|_| true), 4);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn skip_while_scans_all_chunks() {
+        let mut reader = (b"  " as &[u8]).chain(b"  foo" as &[u8]);
+        assert_eq!(reader.skip_while(|byte| byte == b' '), 4);
+        assert_eq!(reader.rest(), b"foo");
+
+        let mut reader = (b"fo" as &[u8]).chain(b"o bar" as &[u8]);
+        assert_eq!(reader.take_while(|byte| byte != b' '), b"foo");
+        assert_eq!(reader.rest(), b" bar");
+    }
+
+    #[test]
+    fn it_reads_integer_arrays() {
+        let mut reader: &'static [u8] = b"\x00\x01\x00\x02\x00\x03\x00";
+        assert_eq!(
+            reader.read_with::<[u16; 3], _>(BigEndian).unwrap(),
+            [1, 2, 3]
+        );
+        assert_eq!(reader, b"\x00");
+
+        let mut reader: &'static [u8] = b"\x01\x00\x02";
+        assert!(reader
+            .read_with::<[u16; 2], _>(LittleEndian)
+            .unwrap_err()
+            .is_end());
+    }
+
+    #[test]
+    fn read_array_advances_reader() {
+        let mut reader: &'static [u8] = b"\x00\x11\x22\x33\x44\x55\x66\x77";
+        let mac = reader.read_array::<6>().unwrap();
+        assert_eq!(mac, [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        assert_eq!(reader, b"\x66\x77");
+    }
+
+    #[test]
+    fn read_array_fails_on_truncation() {
+        let mut reader: &'static [u8] = b"\x00\x11\x22";
+        let e = reader.read_array::<6>().unwrap_err();
+        assert!(e.is_end());
+    }
+
     #[test]
     fn derive_read_for_unit_struct() {
         #[derive(Read)]
@@ -595,6 +1481,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn derive_read_uses_default_endianness() {
+        #[derive(Read, Debug, PartialEq)]
+        #[byst(big)]
+        struct Foo {
+            length: u16,
+            value: u32,
+        }
+        assert_read!(
+            Foo,
+            b"\x00\x04\xde\xad\xbe\xef",
+            Foo {
+                length: 4,
+                value: 0xdeadbeef
+            }
+        );
+    }
+
+    #[test]
+    fn derive_read_field_endianness_overrides_default() {
+        #[derive(Read, Debug, PartialEq)]
+        #[byst(little)]
+        struct Bar {
+            tag: u8,
+            #[byst(big)]
+            x: u16,
+            y: u16,
+        }
+        #[derive(Read, Debug, PartialEq)]
+        #[byst(big)]
+        struct Foo {
+            bar: Bar,
+            z: i16,
+        }
+        assert_read!(
+            Foo,
+            b"\x01\x12\x34\x12\x34\xff\xfe",
+            Foo {
+                bar: Bar {
+                    tag: 1,
+                    x: 0x1234,
+                    y: 0x3412
+                },
+                z: -2
+            }
+        );
+    }
+
+    #[test]
+    fn derive_read_nests_structs_under_default_endianness() {
+        #[derive(Read, Debug, PartialEq)]
+        struct Inner {
+            a: u8,
+            #[byst(little)]
+            b: u16,
+        }
+        #[derive(Read, Debug, PartialEq)]
+        #[byst(big)]
+        struct Outer {
+            inner: Inner,
+            c: u16,
+        }
+        assert_read!(
+            Outer,
+            b"\x01\x34\x12\x56\x78",
+            Outer {
+                inner: Inner { a: 1, b: 0x1234 },
+                c: 0x5678
+            }
+        );
+    }
+
     #[test]
     fn derive_read_for_empty_enum() {
         #[derive(Debug, PartialEq, Eq, thiserror::Error)]
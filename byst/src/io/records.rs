@@ -0,0 +1,71 @@
+use super::BufReader;
+
+/// An iterator over successive fixed-size records of a reader.
+///
+/// Each item is a view of the next `size` bytes. Iteration stops once fewer
+/// than `size` bytes remain, and the remaining bytes are left in the reader.
+///
+/// This is created with [`BufReader::records`].
+#[derive(Debug)]
+pub struct Records<R> {
+    reader: R,
+    size: usize,
+}
+
+impl<R> Records<R> {
+    #[inline]
+    pub fn new(reader: R, size: usize) -> Self {
+        Self { reader, size }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufReader> Iterator for Records<R> {
+    type Item = R::View;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+        self.reader.view(self.size).ok()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self
+            .reader
+            .remaining()
+            .checked_div(self.size)
+            .unwrap_or_default();
+        (n, Some(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        io::BufReader,
+        Bytes,
+    };
+
+    #[test]
+    fn it_yields_records() {
+        let mut reader = Bytes::from_static(&[0xab; 30]);
+        let records = reader.records(12).collect::<Vec<_>>();
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|record| *record == [0xab; 12]));
+        assert_eq!(reader.remaining(), 6);
+    }
+
+    #[test]
+    fn it_yields_nothing_for_zero_size() {
+        let mut reader: &[u8] = b"Hello World";
+        assert_eq!(reader.records(0).count(), 0);
+        assert_eq!(reader.remaining(), 11);
+    }
+}
@@ -0,0 +1,159 @@
+use super::{
+    BufReader,
+    End,
+    Seek,
+};
+use crate::{
+    buf::Length,
+    impl_me,
+    util::for_each_chunk,
+    Buf,
+    BytesMut,
+};
+
+/// A reader that records all bytes read from it into a [`BytesMut`].
+///
+/// Bytes are recorded when they're consumed through any method, e.g.
+/// [`BufReader::view`], [`BufReader::advance`], [`BufReader::rest`], or
+/// [`Reader::read_into`](super::Reader::read_into). Peeking doesn't record
+/// anything. It is created with [`BufReader::tee`].
+///
+/// Seeking back to a previous position discards the bytes recorded since, so
+/// the sink always contains exactly the bytes consumed up to the current
+/// position. Seeking forward doesn't record the skipped bytes.
+#[derive(Debug)]
+pub struct Tee<'a, R> {
+    inner: R,
+    sink: &'a mut BytesMut,
+}
+
+impl<'a, R> Tee<'a, R> {
+    #[inline]
+    pub fn new(inner: R, sink: &'a mut BytesMut) -> Self {
+        Self { inner, sink }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn record(&mut self, view: impl Buf) {
+        for_each_chunk(&mut view.reader(), |chunk| {
+            self.sink.extend_from_slice(chunk)
+        });
+    }
+}
+
+impl<'a, R: BufReader> BufReader for Tee<'a, R> {
+    type View = R::View;
+
+    #[inline]
+    fn peek_chunk(&self) -> Option<&[u8]> {
+        self.inner.peek_chunk()
+    }
+
+    #[inline]
+    fn view(&mut self, length: usize) -> Result<Self::View, End> {
+        let view = self.inner.view(length)?;
+        self.record(&view);
+        Ok(view)
+    }
+
+    #[inline]
+    fn peek_view(&self, length: usize) -> Result<Self::View, End> {
+        self.inner.peek_view(length)
+    }
+
+    #[inline]
+    fn rest(&mut self) -> Self::View {
+        let view = self.inner.rest();
+        self.record(&view);
+        view
+    }
+
+    #[inline]
+    fn peek_rest(&self) -> Self::View {
+        self.inner.peek_rest()
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), End> {
+        self.view(by)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+}
+
+impl<'a, R: Seek> Seek for Tee<'a, R> {
+    /// The inner reader's position, and the number of bytes recorded at that
+    /// position.
+    type Position = (R::Position, usize);
+
+    #[inline]
+    fn tell(&self) -> Self::Position {
+        (self.inner.tell(), self.sink.len())
+    }
+
+    fn seek(&mut self, position: &Self::Position) -> Self::Position {
+        let old_position = (self.inner.seek(&position.0), self.sink.len());
+        if position.1 < self.sink.len() {
+            self.sink
+                .split_off(position.1)
+                .expect("Discarding recorded bytes failed");
+        }
+        old_position
+    }
+}
+
+impl_me! {
+    impl['a, R: BufReader] Reader for Tee<'a, R> as BufReader;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        endianness::BigEndian,
+        io::{
+            BufReader,
+            ReaderExt,
+            Seek,
+        },
+        BytesMut,
+    };
+
+    #[test]
+    fn it_records_consumed_bytes() {
+        let mut sink = BytesMut::new();
+        let mut reader = (b"\x00\x05Hello\x12\x34 World" as &[u8]).tee(&mut sink);
+
+        let length = reader.read_with::<u16, _>(BigEndian).unwrap();
+        assert_eq!(
+            BufReader::view(&mut reader, length.into()).unwrap(),
+            b"Hello"
+        );
+        reader.peek_view(2).unwrap();
+        BufReader::advance(&mut reader, 2).unwrap();
+        assert_eq!(reader.remaining(), 6);
+
+        assert_eq!(sink, b"\x00\x05Hello\x12\x34");
+    }
+
+    #[test]
+    fn seeking_back_discards_recorded_bytes() {
+        let mut sink = BytesMut::new();
+        let mut reader = (b"Hello World" as &[u8]).tee(&mut sink);
+
+        BufReader::advance(&mut reader, 6).unwrap();
+        let position = reader.tell();
+        reader.rest();
+        reader.seek(&position);
+        assert_eq!(reader.remaining(), 5);
+
+        assert_eq!(sink, b"Hello ");
+    }
+}
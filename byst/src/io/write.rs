@@ -1,13 +1,29 @@
-use std::{
+use alloc::vec::Vec;
+use core::{
     convert::Infallible,
+    hash::Hasher,
     marker::PhantomData,
+    net::{
+        Ipv4Addr,
+        Ipv6Addr,
+        SocketAddrV4,
+        SocketAddrV6,
+    },
 };
 
 use byst_macros::for_tuple;
 
-use super::Limit;
+use super::{
+    BufferedWriter,
+    HashingWriter,
+    Limit,
+};
 use crate::{
     buf::Buf,
+    endianness::{
+        Endianness,
+        NetworkEndian,
+    },
     impl_me,
     BufMut,
 };
@@ -42,10 +58,33 @@ pub trait WriterExt: Writer {
         T::write(value, self, context)
     }
 
+    /// Writes an array of `N` bytes.
+    #[inline]
+    fn write_array<const N: usize>(&mut self, array: &[u8; N]) -> Result<(), Self::Error> {
+        self.write_buf(array)
+    }
+
     #[inline]
     fn limit(&mut self, limit: usize) -> Limit<&mut Self> {
         Limit::new(self, limit)
     }
+
+    /// Wraps this writer in a [`HashingWriter`], which feeds all bytes
+    /// written into `hasher`.
+    #[inline]
+    fn hashing<H: Hasher>(&mut self, hasher: H) -> HashingWriter<&mut Self, H> {
+        HashingWriter::new(self, hasher)
+    }
+
+    /// Wraps this writer in a [`BufferedWriter`], which batches writes into
+    /// a buffer of `capacity` bytes.
+    #[inline]
+    fn buffered(&mut self, capacity: usize) -> BufferedWriter<&mut Self>
+    where
+        Self: Sized,
+    {
+        BufferedWriter::new(self, capacity)
+    }
 }
 
 impl<W: Writer> WriterExt for W {}
@@ -70,6 +109,57 @@ pub trait BufWriter: Writer<Error = Full> {
     fn remaining(&self) -> usize;
 
     fn extend(&mut self, with: &[u8]) -> Result<(), Full>;
+
+    /// Writes `count` copies of `byte`.
+    ///
+    /// # Default implementation
+    ///
+    /// The default implementation repeatedly calls [`extend`](Self::extend)
+    /// with a small buffer filled with `byte`.
+    fn fill(&mut self, byte: u8, count: usize) -> Result<(), Full> {
+        let chunk = [byte; 64];
+        let mut written = 0;
+
+        while written < count {
+            let n = core::cmp::min(count - written, chunk.len());
+            self.extend(&chunk[..n]).map_err(|e| {
+                Full {
+                    written: written + e.written,
+                    requested: count,
+                    remaining: e.remaining,
+                }
+            })?;
+            written += n;
+        }
+
+        Ok(())
+    }
+
+    /// Writes all slices in `bufs` in order.
+    ///
+    /// # Default implementation
+    ///
+    /// The default implementation calls [`extend`](Self::extend) for each
+    /// slice. If the writer becomes full, the slices before the failing one
+    /// will have been written, and the returned error reports the total number
+    /// of bytes written.
+    fn write_all_vectored(&mut self, bufs: &[&[u8]]) -> Result<(), Full> {
+        let requested = bufs.iter().map(|buf| buf.len()).sum();
+        let mut written = 0;
+
+        for buf in bufs {
+            self.extend(buf).map_err(|e| {
+                Full {
+                    written: written + e.written,
+                    requested,
+                    remaining: e.remaining,
+                }
+            })?;
+            written += buf.len();
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, thiserror::Error)]
@@ -115,7 +205,7 @@ impl<'w, W: Writer> Writer for &'w mut W {
 impl_me! {
     impl['a] Writer for &'a mut [u8] as BufWriter;
     impl['a] Write<_, ()> for &'a [u8] as Writer::write_buf;
-    //impl['a] Writer for &'a mut Vec<u8> as BufWriter;
+    impl['a] Writer for &'a mut Vec<u8> as BufWriter;
 }
 
 impl<'b, W: BufWriter> BufWriter for &'b mut W {
@@ -160,6 +250,75 @@ impl<'b, W: BufWriter> BufWriter for &'b mut W {
     fn extend(&mut self, with: &[u8]) -> Result<(), Full> {
         W::extend(*self, with)
     }
+
+    #[inline]
+    fn fill(&mut self, byte: u8, count: usize) -> Result<(), Full> {
+        W::fill(*self, byte, count)
+    }
+}
+
+/// Appends to the [`Vec`].
+///
+/// Unlike [`VecWriter`], this doesn't overwrite existing bytes. Since there is
+/// no pre-allocated region to write to, [`peek_chunk_mut`] returns `None`, and
+/// peeking views fails for non-zero lengths.
+///
+/// [`VecWriter`]: crate::buf::VecWriter
+/// [`peek_chunk_mut`]: BufWriter::peek_chunk_mut
+impl<'v> BufWriter for &'v mut Vec<u8> {
+    type ViewMut<'a> = &'a mut [u8] where Self: 'a;
+
+    #[inline]
+    fn peek_chunk_mut(&mut self) -> Option<&mut [u8]> {
+        None
+    }
+
+    fn view_mut(&mut self, length: usize) -> Result<Self::ViewMut<'_>, Full> {
+        let start = self.len();
+        self.resize(start + length, 0);
+        Ok(&mut self[start..])
+    }
+
+    #[inline]
+    fn peek_view_mut(&mut self, length: usize) -> Result<Self::ViewMut<'_>, Full> {
+        if length == 0 {
+            Ok(&mut [])
+        }
+        else {
+            Err(Full {
+                written: 0,
+                requested: length,
+                remaining: 0,
+            })
+        }
+    }
+
+    #[inline]
+    fn rest_mut(&mut self) -> Self::ViewMut<'_> {
+        &mut []
+    }
+
+    #[inline]
+    fn peek_rest_mut(&mut self) -> Self::ViewMut<'_> {
+        &mut []
+    }
+
+    #[inline]
+    fn advance(&mut self, by: usize) -> Result<(), Full> {
+        self.resize(self.len() + by, 0);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        usize::MAX
+    }
+
+    #[inline]
+    fn extend(&mut self, with: &[u8]) -> Result<(), Full> {
+        self.extend_from_slice(with);
+        Ok(())
+    }
 }
 
 impl<'b> BufWriter for &'b mut [u8] {
@@ -177,7 +336,7 @@ impl<'b> BufWriter for &'b mut [u8] {
 
     fn view_mut(&mut self, length: usize) -> Result<Self::ViewMut<'_>, crate::io::Full> {
         if length <= self.len() {
-            let (left, right) = std::mem::take(self).split_at_mut(length);
+            let (left, right) = core::mem::take(self).split_at_mut(length);
             *self = right;
             Ok(left)
         }
@@ -206,7 +365,7 @@ impl<'b> BufWriter for &'b mut [u8] {
 
     #[inline]
     fn rest_mut(&mut self) -> Self::ViewMut<'_> {
-        std::mem::take(self)
+        core::mem::take(self)
     }
 
     #[inline]
@@ -217,7 +376,7 @@ impl<'b> BufWriter for &'b mut [u8] {
     #[inline]
     fn advance(&mut self, by: usize) -> Result<(), Full> {
         if by <= self.len() {
-            let (_, rest) = std::mem::take(self).split_at_mut(by);
+            let (_, rest) = core::mem::take(self).split_at_mut(by);
             *self = rest;
             Ok(())
         }
@@ -238,7 +397,7 @@ impl<'b> BufWriter for &'b mut [u8] {
     #[inline]
     fn extend(&mut self, with: &[u8]) -> Result<(), Full> {
         if with.len() <= self.len() {
-            let (dest, rest) = std::mem::take(self).split_at_mut(with.len());
+            let (dest, rest) = core::mem::take(self).split_at_mut(with.len());
             dest.copy_from_slice(with);
             *self = rest;
             Ok(())
@@ -280,6 +439,20 @@ impl<W: Writer, const N: usize> Write<W, ()> for [u8; N] {
     }
 }
 
+/// Writes the elements in sequence, with the same endianness.
+///
+/// This is only implemented for endianness contexts, since it would conflict
+/// with the implementation for byte arrays otherwise.
+impl<W, E: Endianness + Copy, T: Write<W, E>, const N: usize> Write<W, E> for [T; N] {
+    type Error = T::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, context: E) -> Result<(), Self::Error> {
+        self.iter()
+            .try_for_each(|element| element.write(writer, context))
+    }
+}
+
 impl<W: Writer> Write<W, ()> for u8 {
     type Error = <W as Writer>::Error;
 
@@ -289,6 +462,15 @@ impl<W: Writer> Write<W, ()> for u8 {
     }
 }
 
+impl<W: Writer> Write<W, ()> for bool {
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, _context: ()) -> Result<(), Self::Error> {
+        writer.write(&u8::from(*self))
+    }
+}
+
 impl<W: Writer> Write<W, ()> for i8 {
     type Error = <W as Writer>::Error;
 
@@ -298,6 +480,68 @@ impl<W: Writer> Write<W, ()> for i8 {
     }
 }
 
+/// Single bytes have no byte order, so they can be written with any
+/// endianness.
+impl<W: Writer, E: Endianness> Write<W, E> for u8 {
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, _context: E) -> Result<(), Self::Error> {
+        writer.write(self)
+    }
+}
+
+impl<W: Writer, E: Endianness> Write<W, E> for i8 {
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, _context: E) -> Result<(), Self::Error> {
+        writer.write(self)
+    }
+}
+
+impl<W: Writer> Write<W, ()> for Ipv4Addr {
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, _context: ()) -> Result<(), Self::Error> {
+        writer.write(&self.octets())
+    }
+}
+
+impl<W: Writer> Write<W, ()> for Ipv6Addr {
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, _context: ()) -> Result<(), Self::Error> {
+        writer.write(&self.octets())
+    }
+}
+
+/// Writes the address followed by the port, both in network byte order.
+impl<W: Writer> Write<W, ()> for SocketAddrV4 {
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, _context: ()) -> Result<(), Self::Error> {
+        writer.write(self.ip())?;
+        writer.write_with(&self.port(), NetworkEndian)
+    }
+}
+
+/// Writes the address followed by the port, both in network byte order.
+///
+/// The flow info and scope ID are not written.
+impl<W: Writer> Write<W, ()> for SocketAddrV6 {
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, _context: ()) -> Result<(), Self::Error> {
+        writer.write(self.ip())?;
+        writer.write_with(&self.port(), NetworkEndian)
+    }
+}
+
 macro_rules! impl_read_for_tuple {
     (
         $index:tt => $name:ident: $ty:ident
@@ -331,11 +575,25 @@ for_tuple!(impl_read_for_tuple! for 1..=8);
 
 #[cfg(test)]
 mod tests {
-    use std::marker::PhantomData;
+    use std::{
+        marker::PhantomData,
+        net::{
+            Ipv4Addr,
+            Ipv6Addr,
+            SocketAddrV4,
+            SocketAddrV6,
+        },
+    };
 
     use crate::{
         buf::BufMut,
+        endianness::{
+            BigEndian,
+            LittleEndian,
+        },
         io::{
+            BufWriter,
+            ReaderExt,
             Write,
             WriterExt,
         },
@@ -364,6 +622,43 @@ mod tests {
         };
     }
 
+    #[test]
+    fn write_array_writes_all_bytes() {
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        writer.write_array(b"\x00\x11\x22\x33\x44\x55").unwrap();
+        assert_eq!(buf, b"\x00\x11\x22\x33\x44\x55");
+    }
+
+    #[test]
+    fn it_writes_integer_arrays() {
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        writer.write_with(&[1u16, 2, 3], BigEndian).unwrap();
+        writer.write_with(&[0x0102u16], LittleEndian).unwrap();
+        assert_eq!(buf, b"\x00\x01\x00\x02\x00\x03\x02\x01");
+    }
+
+    #[test]
+    fn it_writes_ip_addresses() {
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        writer.write(&Ipv4Addr::new(192, 168, 0, 1)).unwrap();
+        writer
+            .write(&SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 8080))
+            .unwrap();
+        assert_eq!(buf, b"\xc0\xa8\x00\x01\xc0\xa8\x00\x01\x1f\x90");
+
+        let address = SocketAddrV6::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 80, 0, 0);
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        writer.write(&address).unwrap();
+        assert_eq!(buf.len(), 18);
+
+        let mut reader = &buf[..];
+        assert_eq!(reader.read::<SocketAddrV6>().unwrap(), address);
+    }
+
     #[test]
     fn derive_write_for_unit_struct() {
         #[derive(Write, Default)]
@@ -457,4 +752,80 @@ mod tests {
             b"\x12\x34\x12\x34\x12\x34"
         );
     }
+
+    #[test]
+    fn derive_write_uses_default_endianness() {
+        #[derive(Write, Default, Debug, PartialEq)]
+        #[byst(big)]
+        struct Foo {
+            tag: u8,
+            length: u16,
+            #[byst(little)]
+            value: u32,
+        }
+        assert_write!(
+            Foo {
+                tag: 0x01,
+                length: 0x1234,
+                value: 0xdeadbeef
+            },
+            b"\x01\x12\x34\xef\xbe\xad\xde"
+        );
+    }
+
+    #[test]
+    fn derive_write_nests_structs_under_default_endianness() {
+        #[derive(Write, Default, Debug, PartialEq)]
+        struct Inner {
+            a: u8,
+            #[byst(little)]
+            b: u16,
+        }
+        #[derive(Write, Default, Debug, PartialEq)]
+        #[byst(big)]
+        struct Outer {
+            inner: Inner,
+            c: u16,
+        }
+        assert_write!(
+            Outer {
+                inner: Inner { a: 1, b: 0x1234 },
+                c: 0x5678
+            },
+            b"\x01\x34\x12\x56\x78"
+        );
+    }
+
+    #[test]
+    fn fill_writes_repeated_bytes() {
+        let mut buf = [0u8; 100];
+        let mut writer = &mut buf[..];
+        BufWriter::fill(&mut writer, 0xff, 99).unwrap();
+        let error = BufWriter::fill(&mut writer, 0xab, 2).unwrap_err();
+        assert_eq!(error.requested, 2);
+        assert_eq!(buf[..99], [0xff; 99]);
+        assert_eq!(buf[99], 0);
+    }
+
+    #[test]
+    fn write_all_vectored_writes_concatenation() {
+        let mut buf = vec![];
+        let mut writer = buf.writer();
+        writer
+            .write_all_vectored(&[b"Hello", b" ", b"World"])
+            .unwrap();
+        assert_eq!(buf, b"Hello World");
+    }
+
+    #[test]
+    fn write_all_vectored_reports_total_written() {
+        let mut buf = [0u8; 8];
+        let mut writer = &mut buf[..];
+        let error = writer
+            .write_all_vectored(&[b"abc", b"defgh", b"ijk"])
+            .unwrap_err();
+        assert_eq!(error.written, 8);
+        assert_eq!(error.requested, 11);
+        assert_eq!(&buf, b"abcdefgh");
+    }
 }
@@ -1,7 +1,23 @@
 //! bytes, bytter, `byst`!
 //!
 //! Read and write bytes on steriods!
+//!
+//! # `no_std`
+//!
+//! This crate can be used without `std` (but with `alloc`) by disabling the
+//! default `std` feature. The following APIs are only available with `std`:
+//!
+//! - Vectored I/O with [`std::io::IoSlice`] and [`std::io::IoSliceMut`] (e.g.
+//!   [`BufReader::peek_io_slices`](io::BufReader::peek_io_slices) and
+//!   [`BufMut::io_slices_mut`]).
+//! - Conversions from [`End`](io::End) into [`std::io::Error`].
+//! - [`BufferPool`](buf::pool::BufferPool), since it needs a mutex.
+//! - [`UnixTimestamp`](time::UnixTimestamp), since it wraps
+//!   [`std::time::SystemTime`].
+//! - Memory-mapped buffers (the `mmap` feature).
+//! - Parallel checksums and searches (the `rayon` feature).
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 // enabled nightly features
 // todo: we might get around these by just copying their source code.
 
@@ -14,15 +30,28 @@
 #![feature(generic_const_exprs)]
 // required by `crate::buf::slab`
 #![feature(new_uninit, slice_ptr_get)]
+// required by `crate::io::read`
+#![feature(array_try_from_fn)]
+
+extern crate alloc;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bits;
 pub mod buf;
 pub mod bytes;
+#[cfg(feature = "bytes-compat")]
+mod bytes_compat;
+#[cfg(feature = "checksum")]
+pub mod checksum;
 mod copy;
 pub mod endianness;
 pub mod hexdump;
 pub mod io;
+#[cfg(feature = "rayon")]
+pub mod parallel;
 mod range;
+pub mod time;
 pub mod util;
 
 pub use self::{
@@ -36,7 +65,9 @@ pub use self::{
     },
     copy::{
         copy,
+        copy_grow,
         copy_io,
+        copy_n,
         copy_range,
     },
     range::{
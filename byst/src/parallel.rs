@@ -0,0 +1,111 @@
+//! Parallel checksums and searches over large contiguous buffers.
+//!
+//! The buffer is partitioned into ranges of [`PARTITION_SIZE`] bytes, which
+//! are processed in parallel with [`rayon`]. Buffers smaller than that are
+//! processed on the current thread. These are also available as
+//! [`BufExt::par_crc32`] and [`BufExt::par_find`].
+
+use rayon::{
+    iter::{
+        IntoParallelIterator,
+        ParallelIterator,
+    },
+    slice::ParallelSlice,
+};
+
+use crate::{
+    buf::BufExt,
+    checksum::crc32_combine,
+    Buf,
+};
+
+/// Number of bytes processed by a single task.
+pub const PARTITION_SIZE: usize = 0x10000;
+
+/// Computes the CRC-32 (IEEE) checksum of `bytes` in parallel.
+///
+/// The checksums of the partitions are combined with [`crc32_combine`].
+pub fn crc32(bytes: &[u8]) -> u32 {
+    if bytes.len() <= PARTITION_SIZE {
+        return bytes.crc32();
+    }
+
+    let (crc, _) = bytes
+        .par_chunks(PARTITION_SIZE)
+        .map(|partition| (partition.crc32(), partition.len()))
+        .reduce(
+            || (0, 0),
+            |(left_crc, left_len), (right_crc, right_len)| {
+                (
+                    crc32_combine(left_crc, right_crc, right_len),
+                    left_len + right_len,
+                )
+            },
+        );
+    crc
+}
+
+/// Returns the offset of the first occurrence of `needle` in `haystack`,
+/// searching in parallel.
+///
+/// Each partition is searched together with the first `needle.len() - 1`
+/// bytes of the following partition, so that occurrences starting in a
+/// partition, but ending in the next one, are found too.
+pub fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if haystack.len() <= PARTITION_SIZE || needle.is_empty() {
+        return haystack.find(needle);
+    }
+
+    let overlap = needle.len() - 1;
+    (0..haystack.len().div_ceil(PARTITION_SIZE))
+        .into_par_iter()
+        .find_map_first(|i| {
+            let start = i * PARTITION_SIZE;
+            let end = (start + PARTITION_SIZE + overlap).min(haystack.len());
+            let partition = &haystack[start..end];
+            partition.find(needle).map(|offset| start + offset)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        crc32,
+        find,
+        PARTITION_SIZE,
+    };
+    use crate::{
+        buf::BufExt,
+        Buf,
+    };
+
+    fn haystack() -> Vec<u8> {
+        (0..3 * 1024 * 1024 + 123)
+            .map(|i: u32| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn crc32_equals_sequential() {
+        let bytes = haystack();
+        assert_eq!(crc32(&bytes), bytes.crc32());
+        assert_eq!(bytes.par_crc32(), bytes.crc32());
+    }
+
+    #[test]
+    fn find_equals_sequential() {
+        let mut bytes = haystack();
+        let needle = b"needle in a haystack";
+        assert_eq!(find(&bytes, needle), bytes.find(needle));
+
+        // an occurrence spanning two partitions, followed by one that doesn't
+        let spanning = 5 * PARTITION_SIZE - 7;
+        bytes[spanning..][..needle.len()].copy_from_slice(needle);
+        bytes[7 * PARTITION_SIZE..][..needle.len()].copy_from_slice(needle);
+        assert_eq!(find(&bytes, needle), Some(spanning));
+        assert_eq!(bytes.par_find(needle), bytes.find(needle));
+
+        let tail = &bytes[bytes.len() - 5..];
+        assert_eq!(find(&bytes, tail), bytes.find(tail));
+    }
+}
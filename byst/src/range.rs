@@ -1,4 +1,4 @@
-use std::{
+use core::{
     fmt::Debug,
     ops::{
         Bound,
@@ -14,14 +14,17 @@ pub struct Range {
 
 impl Range {
     #[inline]
-    fn from_range_bounds(range: impl RangeBounds<usize>) -> Self {
+    pub(crate) fn from_range_bounds(range: impl RangeBounds<usize>) -> Self {
+        // note: inclusive ends (and exclusive starts) at `usize::MAX` saturate. no
+        // buffer can contain them anyway, so the range will be out of bounds
+        // for any buffer.
         let start = match range.start_bound() {
             Bound::Included(start) => Some(*start),
-            Bound::Excluded(start) => Some(*start + 1),
+            Bound::Excluded(start) => Some(start.saturating_add(1)),
             Bound::Unbounded => None,
         };
         let end = match range.end_bound() {
-            Bound::Included(end) => Some(*end + 1),
+            Bound::Included(end) => Some(end.saturating_add(1)),
             Bound::Excluded(end) => Some(*end),
             Bound::Unbounded => None,
         };
@@ -48,12 +51,12 @@ macro_rules! impl_from_range_bounds {
 }
 
 impl_from_range_bounds! {
-    std::ops::Range<usize>;
-    std::ops::RangeFrom<usize>;
-    std::ops::RangeFull;
-    std::ops::RangeInclusive<usize>;
-    std::ops::RangeTo<usize>;
-    std::ops::RangeToInclusive<usize>;
+    core::ops::Range<usize>;
+    core::ops::RangeFrom<usize>;
+    core::ops::RangeFull;
+    core::ops::RangeInclusive<usize>;
+    core::ops::RangeTo<usize>;
+    core::ops::RangeToInclusive<usize>;
 }
 
 impl From<usize> for Range {
@@ -157,7 +160,10 @@ impl Range {
         };
 
         let index_start = if let Some(range_start) = self.start {
-            let index_start = range_start + start;
+            let Some(index_start) = range_start.checked_add(start)
+            else {
+                return err();
+            };
             if index_start > end {
                 return err();
             }
@@ -168,7 +174,10 @@ impl Range {
         };
 
         let index_end = if let Some(range_end) = self.end {
-            let index_end = range_end + start;
+            let Some(index_end) = range_end.checked_add(start)
+            else {
+                return err();
+            };
             if index_end > end {
                 return err();
             }
@@ -188,12 +197,94 @@ impl Range {
         Ok((index_start, index_end))
     }
 
+    /// Returns the indices of the range in `start..end`, clamping them to
+    /// these bounds.
+    ///
+    /// Unlike [`Range::indices_checked_in`] this never fails. If the range
+    /// lies completely outside of the bounds, or its end is before its start,
+    /// an empty range is returned. The range is relative to `start`, see
+    /// [`Range::clamp`] for clamping to absolute bounds.
+    pub fn indices_clamped_in(&self, start: usize, end: usize) -> (usize, usize) {
+        let clamp = |index: usize| start.saturating_add(index).min(end);
+        let index_start = self.start.map_or(start, clamp);
+        let index_end = self.end.map_or(end, clamp).max(index_start);
+        (index_start, index_end)
+    }
+
+    /// Translates the range by `delta`.
+    ///
+    /// A range without a start starts at 0, so the shifted range starts at
+    /// `delta`. A range without an end stays unbounded. Both ends saturate
+    /// at `0` and `usize::MAX`.
+    #[inline]
+    pub fn shift(self, delta: isize) -> Range {
+        Range {
+            start: Some(self.start.unwrap_or_default().saturating_add_signed(delta)),
+            end: self.end.map(|end| end.saturating_add_signed(delta)),
+        }
+    }
+
+    /// Clamps the range to `min..max`.
+    ///
+    /// Unbounded ends are resolved to `min` and `max` respectively. If the
+    /// end is before the start, the returned range is empty.
+    ///
+    /// Unlike [`Range::indices_clamped_in`], `min` and `max` are absolute
+    /// bounds, i.e. the range is not interpreted relative to `min`. Clamping
+    /// `2..4` to `3..10` gives `3..4`, while its indices clamped in `3..10`
+    /// are `(5, 7)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    #[inline]
+    pub fn clamp(self, min: usize, max: usize) -> Range {
+        assert!(min <= max, "Can't clamp to {min}..{max}");
+        let (start, end) = self.indices_clamped_in(0, max);
+        Range {
+            start: Some(start.max(min)),
+            end: Some(end.max(min)),
+        }
+    }
+
     #[inline]
     pub fn len_in(&self, start: usize, end: usize) -> usize {
         let (start, end) = self.indices_unchecked_in(start, end);
         end.saturating_sub(start)
     }
 
+    /// Returns the length of the range, if it has an end.
+    ///
+    /// A range without a start starts at 0. If the end is before the start,
+    /// the length is 0.
+    #[inline]
+    pub fn len(&self) -> Option<usize> {
+        Some(self.end?.saturating_sub(self.start.unwrap_or_default()))
+    }
+
+    /// Returns `true` if the range is known to be empty, i.e. it has an end
+    /// that isn't after its start.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == Some(0)
+    }
+
+    /// Returns the overlap of `self` and `other`.
+    ///
+    /// If the ranges don't overlap, the returned range is empty.
+    pub fn intersect(&self, other: impl Into<Range>) -> Range {
+        let other = other.into();
+        let start = match (self.start, other.start) {
+            (Some(left), Some(right)) => Some(left.max(right)),
+            (start, None) | (None, start) => start,
+        };
+        let end = match (self.end, other.end) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (end, None) | (None, end) => end,
+        };
+        Range { start, end }
+    }
+
     pub fn contains(&self, other: impl Into<Range>) -> bool {
         let other = other.into();
         match (self.start, other.start) {
@@ -224,7 +315,7 @@ impl Range {
 }
 
 impl Debug for Range {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if let Some(start) = self.start {
             write!(f, "{start}")?;
         }
@@ -246,6 +337,10 @@ pub struct RangeOutOfBounds {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        bytes::SmallBytes,
+        Buf,
+    };
 
     #[test]
     fn unbounded_range() {
@@ -268,4 +363,110 @@ mod tests {
         assert_eq!(r.indices_checked_in(12, 34).unwrap(), (12, 16));
         assert_eq!(r.len_in(12, 34), 4);
     }
+
+    #[test]
+    fn it_clamps_indices() {
+        assert_eq!(Range::from(5..100).indices_clamped_in(0, 10), (5, 10));
+        assert_eq!(Range::from(..).indices_clamped_in(0, 10), (0, 10));
+        assert_eq!(Range::from(20..30).indices_clamped_in(0, 10), (10, 10));
+        assert_eq!(Range::from(2..4).indices_clamped_in(12, 34), (14, 16));
+        assert_eq!(
+            Range {
+                start: Some(7),
+                end: Some(3)
+            }
+            .indices_clamped_in(0, 10),
+            (7, 7)
+        );
+        assert_eq!(
+            Range::from(5..=usize::MAX).indices_clamped_in(0, 10),
+            (5, 10)
+        );
+    }
+
+    #[test]
+    fn it_views_clamped() {
+        let buf = b"0123456789";
+
+        assert_eq!(buf.view_clamped(5..100), b"56789");
+        assert_eq!(buf.view_clamped(..3), b"012");
+        assert!(buf.view_clamped(20..).is_empty());
+    }
+
+    #[test]
+    fn it_views_with_all_range_syntaxes() {
+        let buf = b"0123456789";
+
+        assert_eq!(buf.view(2..5).unwrap(), b"234");
+        assert_eq!(buf.view(2..=5).unwrap(), b"2345");
+        assert_eq!(buf.view(..3).unwrap(), b"012");
+        assert_eq!(buf.view(..=3).unwrap(), b"0123");
+        assert_eq!(buf.view(7..).unwrap(), b"789");
+        assert_eq!(buf.view(..).unwrap(), b"0123456789");
+        assert_eq!(buf.view(..=9).unwrap(), b"0123456789");
+        assert!(buf.view(..=10).is_err());
+    }
+
+    #[test]
+    fn inclusive_end_at_usize_max_does_not_overflow() {
+        let r = Range::from(..=usize::MAX);
+        assert_eq!(r.end, Some(usize::MAX));
+        assert!(b"0123456789".view(r).is_err());
+        assert!(b"0123456789".view(5..=usize::MAX).is_err());
+    }
+
+    #[test]
+    fn inclusive_end_at_usize_max_does_not_overflow_in_sub_view() {
+        let bytes = SmallBytes::from(b"0123456789" as &[u8]);
+        let view = bytes.view(2..).unwrap();
+        assert!(view.view(..=usize::MAX).is_err());
+        assert!(view.view(5..=usize::MAX).is_err());
+        assert!(view.view(usize::MAX..).is_err());
+    }
+
+    #[test]
+    fn range_len() {
+        assert_eq!(Range::from(2..7).len(), Some(5));
+        assert_eq!(Range::from(..7).len(), Some(7));
+        assert_eq!(Range::from(2..).len(), None);
+        assert_eq!(Range::from(..).len(), None);
+        assert_eq!(Range::from((7, 2)).len(), Some(0));
+
+        assert!(Range::from(2..2).is_empty());
+        assert!(!Range::from(2..7).is_empty());
+        assert!(!Range::from(2..).is_empty());
+    }
+
+    #[test]
+    fn range_shift() {
+        assert_eq!(Range::from(2..5).shift(10), Range::from(12..15));
+        assert_eq!(Range::from(12..15).shift(-10), Range::from(2..5));
+        assert_eq!(Range::from(..5).shift(3), Range::from(3..8));
+        assert_eq!(Range::from(2..).shift(3), Range::from(5..));
+        assert_eq!(Range::from(2..5).shift(-4), Range::from(0..1));
+        assert_eq!(
+            Range::from(2..5).shift(isize::MAX).shift(isize::MAX).end,
+            Some(usize::MAX)
+        );
+    }
+
+    #[test]
+    fn range_clamp() {
+        let len = 10;
+        assert_eq!(Range::from(..).clamp(0, len), Range::from(0..len));
+        assert_eq!(Range::from(2..20).clamp(0, len), Range::from(2..10));
+        assert_eq!(Range::from(..5).clamp(3, len), Range::from(3..5));
+        assert!(Range::from(20..30).clamp(0, len).is_empty());
+        assert!(Range::from((7, 3)).clamp(0, len).is_empty());
+        assert_eq!(Range::from(2..4).clamp(3, len), Range::from(3..4));
+        assert_eq!(Range::from(2..4).indices_clamped_in(3, len), (5, 7));
+    }
+
+    #[test]
+    fn range_intersect() {
+        assert_eq!(Range::from(2..7).intersect(4..10), Range::from(4..7));
+        assert_eq!(Range::from(2..).intersect(..5), Range::from(2..5));
+        assert_eq!(Range::from(..).intersect(3..), Range::from(3..));
+        assert!(Range::from(2..4).intersect(6..8).is_empty());
+    }
 }
@@ -0,0 +1,239 @@
+//! Wire formats for time values.
+//!
+//! - [`Duration`] is encoded as a `u64` of whole seconds, followed by a `u32`
+//!   of nanoseconds.
+//! - [`UnixTimestamp`] is encoded as an `i64` of seconds since the Unix epoch.
+//!
+//! The endianness is passed as context. Use [`NetworkEndian`] for the
+//! canonical big-endian representation.
+//!
+//! [`NetworkEndian`]: crate::endianness::NetworkEndian
+
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+use crate::{
+    endianness::Endianness,
+    io::{
+        Read,
+        ReadValueError,
+        Reader,
+        ReaderExt,
+        Write,
+        Writer,
+    },
+};
+
+/// The nanoseconds of a [`Duration`] were not less than a second.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid nanoseconds: {0}")]
+pub struct InvalidNanos(pub u32);
+
+/// The seconds of an [`UnixTimestamp`] can't be represented on this platform.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, thiserror::Error)]
+#[error("Timestamp out of range: {0}")]
+pub struct TimestampOutOfRange(pub i64);
+
+impl<R: Reader, E: Endianness + Copy> Read<R, E> for Duration
+where
+    u64: Read<R, E, Error = <R as Reader>::Error>,
+    u32: Read<R, E, Error = <R as Reader>::Error>,
+{
+    type Error = ReadValueError<<R as Reader>::Error, InvalidNanos>;
+
+    /// Reads the seconds as `u64` and the nanoseconds as `u32`.
+    ///
+    /// This fails with [`InvalidNanos`] if the nanoseconds are not less than a
+    /// second.
+    #[inline]
+    fn read(reader: &mut R, context: E) -> Result<Self, Self::Error> {
+        let secs = reader.read_with::<u64, E>(context)?;
+        let nanos = reader.read_with::<u32, E>(context)?;
+        if nanos >= 1_000_000_000 {
+            return Err(ReadValueError::Invalid {
+                value: InvalidNanos(nanos),
+                read: 12,
+            });
+        }
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+impl<W: Writer, E: Endianness + Copy> Write<W, E> for Duration
+where
+    u64: Write<W, E, Error = <W as Writer>::Error>,
+    u32: Write<W, E, Error = <W as Writer>::Error>,
+{
+    type Error = <W as Writer>::Error;
+
+    /// Writes the seconds as `u64` and the nanoseconds as `u32`.
+    #[inline]
+    fn write(&self, writer: &mut W, context: E) -> Result<(), Self::Error> {
+        self.as_secs().write(writer, context)?;
+        self.subsec_nanos().write(writer, context)
+    }
+}
+
+/// A [`SystemTime`] that is read and written as an `i64` of seconds since the
+/// Unix epoch.
+///
+/// Sub-second precision is truncated when writing, i.e. the timestamp is
+/// rounded down to whole seconds. This also applies to times before the
+/// epoch, which are written as negative values.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixTimestamp(pub SystemTime);
+
+#[cfg(feature = "std")]
+impl UnixTimestamp {
+    /// Returns the number of whole seconds since the Unix epoch, rounded
+    /// down.
+    ///
+    /// This saturates, if the value doesn't fit into an `i64`.
+    pub fn as_secs(&self) -> i64 {
+        match self.0.duration_since(UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_secs()).unwrap_or(i64::MAX),
+            Err(before) => {
+                let before = before.duration();
+                let secs = before.as_secs() + u64::from(before.subsec_nanos() > 0);
+                i64::try_from(secs).map_or(i64::MIN, |secs| -secs)
+            }
+        }
+    }
+
+    /// Creates a timestamp from the number of seconds since the Unix epoch.
+    ///
+    /// Returns `None`, if the time can't be represented by [`SystemTime`].
+    pub fn from_secs(secs: i64) -> Option<Self> {
+        let offset = Duration::from_secs(secs.unsigned_abs());
+        let time = if secs >= 0 {
+            UNIX_EPOCH.checked_add(offset)
+        }
+        else {
+            UNIX_EPOCH.checked_sub(offset)
+        };
+        time.map(Self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<SystemTime> for UnixTimestamp {
+    #[inline]
+    fn from(value: SystemTime) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<UnixTimestamp> for SystemTime {
+    #[inline]
+    fn from(value: UnixTimestamp) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Reader, E: Endianness> Read<R, E> for UnixTimestamp
+where
+    i64: Read<R, E, Error = <R as Reader>::Error>,
+{
+    type Error = ReadValueError<<R as Reader>::Error, TimestampOutOfRange>;
+
+    #[inline]
+    fn read(reader: &mut R, context: E) -> Result<Self, Self::Error> {
+        let secs = reader.read_with::<i64, E>(context)?;
+        Self::from_secs(secs).ok_or(ReadValueError::Invalid {
+            value: TimestampOutOfRange(secs),
+            read: 8,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Writer, E: Endianness> Write<W, E> for UnixTimestamp
+where
+    i64: Write<W, E, Error = <W as Writer>::Error>,
+{
+    type Error = <W as Writer>::Error;
+
+    #[inline]
+    fn write(&self, writer: &mut W, context: E) -> Result<(), Self::Error> {
+        self.as_secs().write(writer, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    #[cfg(feature = "std")]
+    use std::time::UNIX_EPOCH;
+
+    use super::InvalidNanos;
+    #[cfg(feature = "std")]
+    use super::UnixTimestamp;
+    use crate::{
+        endianness::NetworkEndian,
+        io::{
+            ReadError,
+            ReadValueError,
+            ReaderExt,
+            WriterExt,
+        },
+        BufMut,
+    };
+
+    #[test]
+    fn duration_round_trips() {
+        let duration = Duration::from_millis(1500);
+        let mut buf = vec![];
+        buf.writer().write_with(&duration, NetworkEndian).unwrap();
+        assert_eq!(buf, b"\x00\x00\x00\x00\x00\x00\x00\x01\x1d\xcd\x65\x00");
+
+        let mut reader: &[u8] = &buf;
+        assert_eq!(
+            reader.read_with::<Duration, _>(NetworkEndian).unwrap(),
+            duration
+        );
+    }
+
+    #[test]
+    fn duration_rejects_invalid_nanos() {
+        let mut reader: &[u8] = b"\x00\x00\x00\x00\x00\x00\x00\x01\x3b\x9a\xca\x00";
+        let error = reader.read_with::<Duration, _>(NetworkEndian).unwrap_err();
+        assert_eq!(
+            error,
+            ReadValueError::Invalid {
+                value: InvalidNanos(1_000_000_000),
+                read: 12,
+            }
+        );
+        assert!(!error.is_end());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unix_timestamp_truncates_to_seconds() {
+        let time = UnixTimestamp(UNIX_EPOCH + Duration::from_millis(1500));
+        let mut buf = vec![];
+        buf.writer().write_with(&time, NetworkEndian).unwrap();
+        assert_eq!(buf, b"\x00\x00\x00\x00\x00\x00\x00\x01");
+
+        let mut reader: &[u8] = &buf;
+        assert_eq!(
+            reader.read_with::<UnixTimestamp, _>(NetworkEndian).unwrap(),
+            UnixTimestamp(UNIX_EPOCH + Duration::from_secs(1))
+        );
+
+        let before = UnixTimestamp(UNIX_EPOCH - Duration::from_millis(1500));
+        assert_eq!(before.as_secs(), -2);
+        assert_eq!(
+            UnixTimestamp::from_secs(-2),
+            Some(UnixTimestamp(UNIX_EPOCH - Duration::from_secs(2)))
+        );
+    }
+}
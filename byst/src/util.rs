@@ -1,9 +1,13 @@
-use std::{
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    convert::Infallible,
     fmt::{
         Debug,
         Display,
     },
     iter::FusedIterator,
+    ops::ControlFlow,
 };
 
 pub use byst_macros::for_tuple;
@@ -129,7 +133,7 @@ impl<I: Iterator + Debug> Debug for Peekable<I>
 where
     I::Item: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Peekable")
             .field("inner", &self.inner)
             .field("peeked", &self.peeked)
@@ -286,7 +290,7 @@ impl<I: Iterator + Debug> Debug for IsEndIter<I>
 where
     I::Item: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("IsEndIter")
             .field("inner", &self.inner)
             .field("iterated_from_start", &self.iterated_from_start)
@@ -302,7 +306,7 @@ pub struct IsEnd<T> {
     pub item: T,
 }
 
-pub fn debug_as_hexdump(f: &mut std::fmt::Formatter, buf: impl Buf) -> std::fmt::Result {
+pub fn debug_as_hexdump(f: &mut core::fmt::Formatter, buf: impl Buf) -> core::fmt::Result {
     use crate::hexdump::{
         Config,
         Hexdump,
@@ -319,6 +323,67 @@ pub fn debug_as_hexdump(f: &mut std::fmt::Formatter, buf: impl Buf) -> std::fmt:
     Display::fmt(&hex, f)
 }
 
+/// Formats `buf` as a continuous hex string.
+///
+/// This is used to implement [`LowerHex`](core::fmt::LowerHex) and
+/// [`UpperHex`](core::fmt::UpperHex). It honors the `#` flag (which prefixes
+/// `0x` or `0X`), and the width, fill, alignment and `0` flags. The buffer is
+/// written chunk by chunk, without allocating.
+pub fn fmt_hex(f: &mut core::fmt::Formatter, buf: impl Buf, upper: bool) -> core::fmt::Result {
+    use core::fmt::{
+        Alignment,
+        Write,
+    };
+
+    let prefix = match (f.alternate(), upper) {
+        (false, _) => "",
+        (true, false) => "0x",
+        (true, true) => "0X",
+    };
+    let padding = f
+        .width()
+        .unwrap_or_default()
+        .saturating_sub(prefix.len() + 2 * buf.len());
+
+    let write_padding = |f: &mut core::fmt::Formatter, fill: char, n: usize| {
+        (0..n).try_for_each(|_| f.write_char(fill))
+    };
+
+    let write_digits = |f: &mut core::fmt::Formatter| {
+        let error = try_for_each_chunk(&mut buf.reader(), |chunk| {
+            chunk
+                .iter()
+                .try_for_each(|byte| {
+                    if upper {
+                        write!(f, "{byte:02X}")
+                    }
+                    else {
+                        write!(f, "{byte:02x}")
+                    }
+                })
+                .map_or_else(ControlFlow::Break, ControlFlow::Continue)
+        });
+        error.map_or(Ok(()), Err)
+    };
+
+    if f.sign_aware_zero_pad() {
+        f.write_str(prefix)?;
+        write_padding(f, '0', padding)?;
+        return write_digits(f);
+    }
+
+    let (pre, post) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    write_padding(f, fill, pre)?;
+    f.write_str(prefix)?;
+    write_digits(f)?;
+    write_padding(f, fill, post)
+}
+
 /// Checks if `needle` is a sub-slice of `haystack`, and returns the index at
 /// which `needle` starts in `haystack`.
 pub fn sub_slice_index(haystack: &[u8], needle: &[u8]) -> Option<usize> {
@@ -331,14 +396,43 @@ pub fn sub_slice_index(haystack: &[u8], needle: &[u8]) -> Option<usize> {
         .then(|| needle_start - haystack_start)
 }
 
-pub fn buf_eq(left: impl Buf, right: impl Buf) -> bool {
-    let left_len = left.len();
-    let right_len = right.len();
+/// Calls `f` with each chunk of `reader`, advancing the reader past it.
+pub(crate) fn for_each_chunk<R: BufReader + ?Sized>(reader: &mut R, mut f: impl FnMut(&[u8])) {
+    try_for_each_chunk(reader, |chunk| {
+        f(chunk);
+        ControlFlow::<Infallible>::Continue(())
+    });
+}
+
+/// Calls `f` with each chunk of `reader`, advancing the reader past it, until
+/// `f` breaks.
+///
+/// Returns the value `f` broke with, if any. The reader is left at the start
+/// of the chunk on which `f` broke.
+pub(crate) fn try_for_each_chunk<R: BufReader + ?Sized, T>(
+    reader: &mut R,
+    mut f: impl FnMut(&[u8]) -> ControlFlow<T>,
+) -> Option<T> {
+    while let Some(chunk) = reader.peek_chunk() {
+        if let ControlFlow::Break(value) = f(chunk) {
+            return Some(value);
+        }
+        let n = chunk.len();
+        reader.advance(n).unwrap_or_else(|_| {
+            panic!("Reader returned chunk of length {n}, but failed to advance by {n}.")
+        });
+    }
+    None
+}
 
-    if left_len != right_len {
+pub fn buf_eq(left: impl Buf, right: impl Buf) -> bool {
+    if !left.same_len_as(&right) {
         return false;
     }
 
+    let left_len = left.len();
+    let right_len = right.len();
+
     if left_len == 0 {
         // this also means right_len == 0
         return true;
@@ -347,6 +441,14 @@ pub fn buf_eq(left: impl Buf, right: impl Buf) -> bool {
     let mut left_reader = left.reader();
     let mut right_reader = right.reader();
 
+    if let (Some(left), Some(right)) = (left_reader.peek_chunk(), right_reader.peek_chunk()) {
+        if <[u8]>::len(left) == left_len && <[u8]>::len(right) == right_len {
+            // fast path: both bufs are contiguous, so we can compare them with a single
+            // slice comparison, which lowers to `memcmp`.
+            return left == right;
+        }
+    }
+
     loop {
         match (left_reader.peek_chunk(), right_reader.peek_chunk()) {
             (None, None) => {
@@ -364,7 +466,7 @@ pub fn buf_eq(left: impl Buf, right: impl Buf) -> bool {
             (Some(left), Some(right)) => {
                 let left_len = <[u8]>::len(left);
                 let right_len = <[u8]>::len(right);
-                let n = std::cmp::min(left_len, right_len);
+                let n = core::cmp::min(left_len, right_len);
 
                 if left[..n] != right[..n] {
                     break false;
@@ -385,6 +487,83 @@ pub fn buf_eq(left: impl Buf, right: impl Buf) -> bool {
     }
 }
 
+/// Compares two buffers lexicographically.
+///
+/// This walks both buffers chunk by chunk and stops at the first differing
+/// byte. If one buffer is a prefix of the other, the shorter one is less.
+pub fn buf_cmp(left: impl Buf, right: impl Buf) -> Ordering {
+    let mut left_reader = left.reader();
+    let mut right_reader = right.reader();
+
+    loop {
+        match (left_reader.peek_chunk(), right_reader.peek_chunk()) {
+            (None, None) => break Ordering::Equal,
+            (None, Some(_)) => break Ordering::Less,
+            (Some(_), None) => break Ordering::Greater,
+            (Some(left), Some(right)) => {
+                let n = core::cmp::min(<[u8]>::len(left), <[u8]>::len(right));
+
+                match left[..n].cmp(&right[..n]) {
+                    Ordering::Equal => {}
+                    ordering => break ordering,
+                }
+
+                left_reader
+                    .advance(n)
+                    .expect("Advancing within the current chunk failed");
+                right_reader
+                    .advance(n)
+                    .expect("Advancing within the current chunk failed");
+            }
+        }
+    }
+}
+
+/// Returns the offset of the first occurrence of `needle` in `reader`.
+///
+/// This works across chunk boundaries and takes linear time. It uses the
+/// Knuth-Morris-Pratt algorithm, which looks at every byte of the reader only
+/// once, so no bytes need to be buffered.
+pub fn find_in_reader(mut reader: impl BufReader, needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+
+    // `fallback[i]` is the length of the longest proper prefix of `needle[..=i]`
+    // that is also a suffix of it. on a mismatch after `i + 1` matched bytes, the
+    // search continues with that many matched bytes.
+    let mut fallback = Vec::with_capacity(needle.len());
+    fallback.push(0);
+    let mut matched = 0;
+    for byte in &needle[1..] {
+        while matched > 0 && needle[matched] != *byte {
+            matched = fallback[matched - 1];
+        }
+        if needle[matched] == *byte {
+            matched += 1;
+        }
+        fallback.push(matched);
+    }
+
+    let mut matched = 0;
+    let mut offset = 0;
+    try_for_each_chunk(&mut reader, |chunk| {
+        for (i, byte) in chunk.iter().enumerate() {
+            while matched > 0 && needle[matched] != *byte {
+                matched = fallback[matched - 1];
+            }
+            if needle[matched] == *byte {
+                matched += 1;
+                if matched == needle.len() {
+                    return ControlFlow::Break(offset + i + 1 - needle.len());
+                }
+            }
+        }
+        offset += chunk.len();
+        ControlFlow::Continue(())
+    })
+}
+
 macro_rules! cfg_pub {
     {
         $(#[$attr:meta])*
@@ -459,7 +638,7 @@ macro_rules! impl_me {
         where
             __R: ::byst::io::BufReader<View = $ty>,
         {
-            type Error = ::std::convert::Infallible;
+            type Error = ::core::convert::Infallible;
 
             #[inline]
             fn read(reader: &mut __R, _context: __C) -> Result<Self, Self::Error> {
@@ -526,10 +705,68 @@ macro_rules! impl_me {
 }
 pub use impl_me;
 
+/// A global allocator for tests, that counts allocations.
+#[cfg(test)]
+pub(crate) mod counting_allocator {
+    use std::{
+        alloc::{
+            GlobalAlloc,
+            Layout,
+            System,
+        },
+        cell::Cell,
+    };
+
+    thread_local! {
+        static NUM_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Counts allocations per thread, since tests run in parallel.
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            NUM_ALLOCATIONS.with(|n| n.set(n.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Returns the number of allocations made by the current thread.
+    pub fn num_allocations() -> usize {
+        NUM_ALLOCATIONS.with(|n| n.get())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::buf_eq;
-    use crate::buf::rope::Rope;
+    use std::{
+        cell::Cell,
+        cmp::Ordering,
+    };
+
+    use super::{
+        buf_cmp,
+        buf_eq,
+        find_in_reader,
+    };
+    use crate::{
+        buf::{
+            rope::Rope,
+            segments::Segments,
+            Length,
+        },
+        io::BufReader,
+        Buf,
+        Range,
+        RangeOutOfBounds,
+    };
 
     #[test]
     fn buf_eq_returns_false_for_different_lengths() {
@@ -538,6 +775,48 @@ mod tests {
         assert!(!buf_eq(b"Hello", b""));
     }
 
+    #[test]
+    fn buf_eq_doesnt_read_buffers_with_different_lengths() {
+        struct CountingBuf<'a> {
+            buf: &'a [u8],
+            reads: Cell<usize>,
+        }
+
+        impl<'a> Length for CountingBuf<'a> {
+            fn len(&self) -> usize {
+                self.buf.len()
+            }
+        }
+
+        impl<'a> Buf for CountingBuf<'a> {
+            type View<'b> = &'b [u8] where Self: 'b;
+            type Reader<'b> = &'b [u8] where Self: 'b;
+
+            fn view(&self, range: impl Into<Range>) -> Result<Self::View<'_>, RangeOutOfBounds> {
+                self.reads.set(self.reads.get() + 1);
+                Buf::view(&self.buf, range)
+            }
+
+            fn reader(&self) -> Self::Reader<'_> {
+                self.reads.set(self.reads.get() + 1);
+                self.buf
+            }
+        }
+
+        let left = CountingBuf {
+            buf: b"Hello",
+            reads: Cell::new(0),
+        };
+        let right = CountingBuf {
+            buf: b"Hello!",
+            reads: Cell::new(0),
+        };
+        assert!(!left.same_len_as(&right));
+        assert!(!buf_eq(&left, &right));
+        assert_eq!(left.reads.get(), 0);
+        assert_eq!(right.reads.get(), 0);
+    }
+
     #[test]
     fn buf_eq_returns_false_for_same_length_buf_different_bytes() {
         assert!(!buf_eq(b"Hello", b"World"));
@@ -554,6 +833,15 @@ mod tests {
         assert!(buf_eq(b"Hello", b"Hello"));
     }
 
+    #[test]
+    fn buf_eq_compares_large_contiguous_buffers() {
+        let buf = vec![0xab; 1024 * 1024];
+        let mut other = buf.clone();
+        assert!(buf_eq(&buf, &other));
+        *other.last_mut().unwrap() = 0;
+        assert!(!buf_eq(&buf, &other));
+    }
+
     #[test]
     #[ignore = "Rope not fully implemented"]
     fn buf_eq_returns_true_for_same_contents_but_differently_sized_chunks() {
@@ -580,4 +868,59 @@ mod tests {
         buf2.push(b"World" as &[u8]);
         assert!(buf_eq(buf1, buf2));
     }
+
+    #[test]
+    fn buf_cmp_orders_lexicographically() {
+        assert_eq!(buf_cmp(b"abc", b"abd"), Ordering::Less);
+        assert_eq!(buf_cmp(b"abd", b"abc"), Ordering::Greater);
+        assert_eq!(buf_cmp(b"ab", b"abc"), Ordering::Less);
+        assert_eq!(buf_cmp(b"abc", b"ab"), Ordering::Greater);
+        assert_eq!(buf_cmp(b"abc", b"abc"), Ordering::Equal);
+        assert_eq!(buf_cmp(b"", b""), Ordering::Equal);
+
+        let segments: &[&[u8]] = &[b"a", b"bc", b"d"];
+        assert_eq!(buf_cmp(Segments::new(segments), b"abcd"), Ordering::Equal);
+        assert_eq!(buf_cmp(Segments::new(segments), b"abce"), Ordering::Less);
+        assert_eq!(b"abc".cmp_prefix(&Segments::new(segments)), Ordering::Less);
+    }
+
+    #[test]
+    fn find_in_reader_spans_chunks() {
+        let reader = (b"GET / HTTP/1.1\r" as &[u8]).chain(b"\n\r\nbody" as &[u8]);
+        assert_eq!(find_in_reader(reader, b"\r\n\r\n"), Some(14));
+
+        let reader = (b"Hello" as &[u8]).chain(b" World" as &[u8]);
+        assert_eq!(find_in_reader(reader, b"o W"), Some(4));
+
+        let reader = (b"Hello" as &[u8]).chain(b" World" as &[u8]);
+        assert_eq!(find_in_reader(reader, b"lo!"), None);
+    }
+
+    #[test]
+    fn find_in_reader_backtracks_across_segments() {
+        let segments: &[&[u8]] = &[b"a", b"aa", b"baab", b"", b"aaab", b"c"];
+        let find = |needle: &[u8]| find_in_reader(Segments::new(segments).reader(), needle);
+        assert_eq!(find(b"aab"), Some(1));
+        assert_eq!(find(b"aaab"), Some(0));
+        assert_eq!(find(b"baaab"), Some(6));
+        assert_eq!(find(b"abaa"), Some(2));
+        assert_eq!(find(b"bc"), Some(10));
+        assert_eq!(find(b"aaaa"), None);
+        assert_eq!(find(b""), Some(0));
+
+        let haystack = [b'a'; 100];
+        let segments: Vec<&[u8]> = haystack.chunks(7).collect();
+        let find = |needle: &[u8]| find_in_reader(Segments::new(&segments).reader(), needle);
+        assert_eq!(find(&[b'a'; 50]), Some(0));
+        assert_eq!(find(b"aaab"), None);
+    }
+
+    #[test]
+    fn find_spans_chunks() {
+        let segments: &[&[u8]] = &[b"Hel", b"lo World"];
+        let buf = Segments::new(segments);
+        assert_eq!(buf.find(b"llo"), Some(2));
+        assert_eq!(buf.find(b"World"), Some(6));
+        assert_eq!(buf.find(b"lol"), None);
+    }
 }
@@ -0,0 +1,28 @@
+//! Checks that the core buffer types can be used from a `#![no_std]` crate.
+//!
+//! This is only meaningful when run with `--no-default-features`, but it
+//! passes with `std` too.
+
+#![no_std]
+
+use byst::{
+    buf::arc_buf::ArcBufMut,
+    endianness::BigEndian,
+    io::{
+        BufWriter,
+        ReaderExt,
+    },
+    BufMut,
+    Bytes,
+};
+
+#[test]
+fn it_writes_and_reads_arc_buf_mut() {
+    let mut buf = ArcBufMut::new(16);
+    BufWriter::extend(&mut buf.writer(), b"\x12\x34Hello").unwrap();
+    assert_eq!(buf, b"\x12\x34Hello");
+
+    let mut reader = Bytes::from(buf.freeze());
+    assert_eq!(reader.read_with::<u16, _>(BigEndian).unwrap(), 0x1234);
+    assert_eq!(reader, b"Hello");
+}